@@ -7,14 +7,61 @@
 // except according to those terms.
 
 use quote::quote;
+use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+
+use crate::parse::FieldTypeHint;
 
 pub(crate) enum GetType {
     Ref,
     Copy_,
     Clone_,
     String_,
+    Path,
+    OsStr,
     Slice(syn::TypeSlice),
     Option_(proc_macro2::TokenStream),
+    OptionDeref(proc_macro2::TokenStream),
+    Result_(syn::Type, syn::Type),
+    Deref(proc_macro2::TokenStream),
+    Iter(syn::Type),
+    Peek(syn::Type),
+    IterDeref(proc_macro2::TokenStream),
+    Contains(syn::Type),
+    Head(syn::TypeSlice, usize),
+    Tail(syn::TypeSlice, usize),
+    Load(proc_macro2::TokenStream),
+    Borrow(syn::Type),
+    CellGet(syn::Type),
+    Map(syn::Path, syn::Type),
+    TryInto(syn::Type),
+    BorrowAs(syn::Type),
+    Ok_(proc_macro2::TokenStream),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum MethodKind {
+    Get,
+    To,
+    Set,
+    Replace,
+    Mut,
+    Take,
+    Pred,
+    Toggle,
+    Bits,
+    Update,
+    Push,
+    At,
+    Into,
+    Redact,
+    Forward,
+    Matches,
+}
+
+pub(crate) enum PredType {
+    Value,
+    IsSome,
+    NotEmpty,
 }
 
 pub(crate) enum FieldType {
@@ -22,72 +69,266 @@ pub(crate) enum FieldType {
     Boolean,
     Character,
     String_,
+    PathBuf,
+    OsString,
+    BoxedStr,
+    BoxedSlice(syn::TypeSlice),
     Array(syn::TypeArray),
     Vector(syn::Type),
+    VecDeque(syn::Type),
+    BinaryHeap(syn::Type),
+    Set(syn::Type),
+    Pointer(syn::Type),
+    Boxed(syn::Type),
+    BoxedDyn(syn::TypeTraitObject),
     Option_(proc_macro2::TokenStream),
+    Result_(syn::Type, syn::Type),
+    Atomic(proc_macro2::TokenStream),
+    RefCell(syn::Type),
+    /// The inner type, and whether it is known to be `Copy`.
+    Cell(syn::Type, bool),
+    Phantom,
+    Reference,
+    Copyable,
+    Cloneable,
     Unhandled,
 }
 
 impl GetType {
-    pub(crate) fn from_field_type(ty: &FieldType) -> Self {
+    pub(crate) fn from_field_type(ty: &FieldType, array_copy_threshold: Option<usize>) -> Self {
         match ty {
+            FieldType::Array(_) if ty.is_small_copy_array(array_copy_threshold) => GetType::Copy_,
             FieldType::Number | FieldType::Boolean | FieldType::Character => GetType::Copy_,
-            FieldType::String_ => GetType::String_,
-            FieldType::Array(type_array) => {
-                let syn::TypeArray {
-                    bracket_token,
-                    elem,
-                    ..
-                } = type_array.clone();
-                GetType::Slice(syn::TypeSlice {
-                    bracket_token,
-                    elem,
-                })
+            FieldType::String_ | FieldType::BoxedStr => GetType::String_,
+            FieldType::PathBuf => GetType::Path,
+            FieldType::OsString => GetType::OsStr,
+            FieldType::Array(_) | FieldType::Vector(_) | FieldType::BoxedSlice(_) => {
+                GetType::Slice(ty.slice_type().expect("the field type should be sliceable"))
             }
-            FieldType::Vector(inner_type) => GetType::Slice(syn::TypeSlice {
-                bracket_token: syn::token::Bracket::default(),
-                elem: Box::new(inner_type.clone()),
-            }),
-            FieldType::Option_(inner_type) => GetType::Option_(inner_type.clone()),
+            FieldType::VecDeque(inner_type) => GetType::Iter(inner_type.clone()),
+            FieldType::BinaryHeap(inner_type) => GetType::Peek(inner_type.clone()),
+            FieldType::Set(_) | FieldType::Pointer(_) => GetType::Ref,
+            FieldType::Boxed(_) | FieldType::BoxedDyn(_) => GetType::Deref(
+                ty.pointer_target()
+                    .expect("the field type should be a pointer"),
+            ),
+            FieldType::Option_(inner_type) => match Self::option_deref_target(inner_type) {
+                Some(target) => GetType::OptionDeref(target),
+                None => GetType::Option_(inner_type.clone()),
+            },
+            FieldType::Result_(ok_type, err_type) => {
+                GetType::Result_(ok_type.clone(), err_type.clone())
+            }
+            FieldType::Atomic(inner_type) => GetType::Load(inner_type.clone()),
+            FieldType::RefCell(inner_type) => GetType::Borrow(inner_type.clone()),
+            FieldType::Cell(inner_type, true) => GetType::CellGet(inner_type.clone()),
+            FieldType::Cell(_, false) => GetType::Ref,
+            FieldType::Phantom | FieldType::Reference | FieldType::Copyable => GetType::Copy_,
+            FieldType::Cloneable => GetType::Clone_,
             FieldType::Unhandled => GetType::Ref,
         }
     }
+
+    /// Iterates over the elements, while `Box<str>` elements are dereferenced to `&str`.
+    pub(crate) fn iter(elem_type: syn::Type) -> Self {
+        match FieldType::from_type_without_hint(&elem_type) {
+            Ok(FieldType::BoxedStr) => GetType::IterDeref(quote!(str)),
+            _ => GetType::Iter(elem_type),
+        }
+    }
+
+    /// Returns `str` for `Box<str>`, or `[T]` for `Box<[T]>` and `Vec<T>`.
+    fn option_deref_target(
+        inner_type: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        let inner_type = syn::parse2::<syn::Type>(inner_type.clone()).ok()?;
+        match FieldType::from_type_without_hint(&inner_type).ok()? {
+            FieldType::BoxedStr => Some(quote!(str)),
+            field_type @ FieldType::BoxedSlice(_) | field_type @ FieldType::Vector(_) => {
+                let type_slice = field_type.slice_type()?;
+                Some(quote!(#type_slice))
+            }
+            _ => None,
+        }
+    }
 }
 
-impl FieldType {
-    pub(crate) fn from_type(ty: &syn::Type) -> Self {
+impl PredType {
+    pub(crate) fn from_field_type(ty: &FieldType) -> Option<Self> {
         match ty {
+            FieldType::Boolean => Some(PredType::Value),
+            FieldType::Option_(_) => Some(PredType::IsSome),
+            FieldType::String_
+            | FieldType::BoxedStr
+            | FieldType::Vector(_)
+            | FieldType::VecDeque(_)
+            | FieldType::BoxedSlice(_) => Some(PredType::NotEmpty),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn default_prefix(&self) -> &'static str {
+        match self {
+            PredType::Value => "is_",
+            PredType::IsSome | PredType::NotEmpty => "has_",
+        }
+    }
+}
+
+impl FieldType {
+    pub(crate) fn from_type(ty: &syn::Type, hint: Option<&FieldTypeHint>) -> ParseResult<Self> {
+        let field_type = match (Self::from_type_without_hint(ty)?, hint) {
+            // Smart pointers are not copied or cloned by default, so the hint takes priority.
+            (FieldType::Unhandled, _)
+            | (FieldType::Pointer(_), Some(_))
+            | (FieldType::Boxed(_), Some(_)) => match hint {
+                Some(FieldTypeHint::Number) => FieldType::Number,
+                Some(FieldTypeHint::Copy_) => FieldType::Copyable,
+                Some(FieldTypeHint::Clone_) => FieldType::Cloneable,
+                None => FieldType::Unhandled,
+            },
+            // The hint is for the inner type, since a `Cell` is never `Copy`.
+            (FieldType::Cell(inner_type, _), Some(FieldTypeHint::Copy_)) => {
+                FieldType::Cell(inner_type, true)
+            }
+            (field_type, _) => field_type,
+        };
+        Ok(field_type)
+    }
+
+    /// Checks whether the path is a single name or a path into `std`, `core` or `alloc`.
+    fn is_std_path(path: &syn::Path) -> bool {
+        let mut segs = path.segments.iter();
+        match (segs.next(), segs.next()) {
+            (Some(_), None) => path.leading_colon.is_none(),
+            (Some(first), Some(_)) => {
+                first.ident == "std" || first.ident == "core" || first.ident == "alloc"
+            }
+            (None, _) => false,
+        }
+    }
+
+    /// Returns the type argument of a generic type, e.g. `T` for `Vec<T>`.
+    ///
+    /// Only a path into the standard library or a name in the prelude, e.g. `Box`, should have a
+    /// type argument, any other type, e.g. a user type named `Cell`, is unknown without it.
+    fn type_argument<'a>(
+        path: &syn::Path,
+        seg: &'a syn::PathSegment,
+    ) -> ParseResult<Option<&'a syn::Type>> {
+        let is_std = path.segments.len() > 1
+            || seg.ident == "Box"
+            || seg.ident == "Vec"
+            || seg.ident == "Option";
+        match &seg.arguments {
+            syn::PathArguments::AngleBracketed(inner) => match inner.args.iter().next() {
+                Some(syn::GenericArgument::Type(inner_type)) => Ok(Some(inner_type)),
+                _ if !is_std => Ok(None),
+                _ => Err(SynError::new(
+                    seg.span(),
+                    format!(
+                        "the first generic argument of `{}` should be a type",
+                        seg.ident
+                    ),
+                )),
+            },
+            _ if !is_std => Ok(None),
+            _ => Err(SynError::new(
+                seg.span(),
+                format!("`{}` should have a type argument", seg.ident),
+            )),
+        }
+    }
+
+    /// Classifies a generic type of the standard library by its type argument.
+    fn from_generic(seg: &syn::PathSegment, inner_type: &syn::Type) -> Self {
+        match (seg.ident.to_string().as_ref(), inner_type) {
+            ("Vec", _) => FieldType::Vector(inner_type.clone()),
+            ("VecDeque", _) => FieldType::VecDeque(inner_type.clone()),
+            ("BinaryHeap", _) => FieldType::BinaryHeap(inner_type.clone()),
+            ("RefCell", _) => FieldType::RefCell(inner_type.clone()),
+            ("Cell", _) => {
+                let is_copy = match Self::from_type_without_hint(inner_type) {
+                    Ok(inner) => inner.is_copy(),
+                    Err(_) => false,
+                };
+                FieldType::Cell(inner_type.clone(), is_copy)
+            }
+            ("Rc", _) | ("Arc", _) => FieldType::Pointer(inner_type.clone()),
+            ("HashSet", _) | ("BTreeSet", _) => FieldType::Set(inner_type.clone()),
+            ("Box", syn::Type::Slice(inner_type)) => FieldType::BoxedSlice(inner_type.clone()),
+            ("Box", syn::Type::Path(inner_type)) if inner_type.path.is_ident("str") => {
+                FieldType::BoxedStr
+            }
+            ("Box", syn::Type::TraitObject(trait_object)) => {
+                FieldType::BoxedDyn(trait_object.clone())
+            }
+            ("Box", _) => FieldType::Boxed(inner_type.clone()),
+            ("Option", _) => FieldType::Option_(quote!(#inner_type)),
+            _ => FieldType::Unhandled,
+        }
+    }
+
+    fn from_type_without_hint(ty: &syn::Type) -> ParseResult<Self> {
+        let field_type = match ty {
+            // An associated type of a qualified path, e.g. `<T as Trait>::Option`, is opaque.
+            syn::Type::Path(type_path) if type_path.qself.is_some() => FieldType::Unhandled,
+            // A path out of the standard library may reuse the name of a standard type.
+            syn::Type::Path(type_path) if !Self::is_std_path(&type_path.path) => {
+                FieldType::Unhandled
+            }
             syn::Type::Path(type_path) => {
-                let segs = &type_path.path.segments;
-                if segs.len() == 1 {
-                    match segs[0].ident.to_string().as_ref() {
+                // Classify by the last segment, so fully qualified paths are handled too.
+                if let Some(seg) = type_path.path.segments.iter().last() {
+                    match seg.ident.to_string().as_ref() {
                         "f32" | "f64" => FieldType::Number,
                         "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => FieldType::Number,
                         "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => FieldType::Number,
+                        "NonZeroI8" | "NonZeroI16" | "NonZeroI32" | "NonZeroI64"
+                        | "NonZeroI128" | "NonZeroIsize" => FieldType::Copyable,
+                        "NonZeroU8" | "NonZeroU16" | "NonZeroU32" | "NonZeroU64"
+                        | "NonZeroU128" | "NonZeroUsize" => FieldType::Copyable,
+                        "Duration" | "Instant" | "SystemTime" => FieldType::Copyable,
+                        "IpAddr" | "Ipv4Addr" | "Ipv6Addr" => FieldType::Copyable,
+                        "SocketAddr" | "SocketAddrV4" | "SocketAddrV6" => FieldType::Copyable,
+                        "AtomicBool" => FieldType::Atomic(quote!(bool)),
+                        "AtomicI8" => FieldType::Atomic(quote!(i8)),
+                        "AtomicI16" => FieldType::Atomic(quote!(i16)),
+                        "AtomicI32" => FieldType::Atomic(quote!(i32)),
+                        "AtomicI64" => FieldType::Atomic(quote!(i64)),
+                        "AtomicIsize" => FieldType::Atomic(quote!(isize)),
+                        "AtomicU8" => FieldType::Atomic(quote!(u8)),
+                        "AtomicU16" => FieldType::Atomic(quote!(u16)),
+                        "AtomicU32" => FieldType::Atomic(quote!(u32)),
+                        "AtomicU64" => FieldType::Atomic(quote!(u64)),
+                        "AtomicUsize" => FieldType::Atomic(quote!(usize)),
+                        "PhantomData" => FieldType::Phantom,
                         "bool" => FieldType::Boolean,
                         "char" => FieldType::Character,
                         "String" => FieldType::String_,
-                        "Vec" => {
-                            if let syn::PathArguments::AngleBracketed(inner) =
-                                &type_path.path.segments[0].arguments
-                            {
-                                if let syn::GenericArgument::Type(ref inner_type) = inner.args[0] {
-                                    FieldType::Vector(inner_type.clone())
-                                } else {
-                                    unreachable!()
-                                }
-                            } else {
-                                unreachable!()
+                        "PathBuf" => FieldType::PathBuf,
+                        "OsString" => FieldType::OsString,
+                        "Vec" | "VecDeque" | "BinaryHeap" | "RefCell" | "Cell" | "Rc" | "Arc"
+                        | "HashSet" | "BTreeSet" | "Box" | "Option" => {
+                            match Self::type_argument(&type_path.path, seg)? {
+                                Some(inner_type) => Self::from_generic(seg, inner_type),
+                                None => FieldType::Unhandled,
                             }
                         }
-                        "Option" => {
-                            if let syn::PathArguments::AngleBracketed(inner) =
-                                &type_path.path.segments[0].arguments
-                            {
-                                let args = &inner.args;
-                                FieldType::Option_(quote!(#args))
+                        "Result" => {
+                            if let syn::PathArguments::AngleBracketed(inner) = &seg.arguments {
+                                // The aliases with a fixed error type, e.g. `io::Result<T>`, are unknown.
+                                let mut args = inner.args.iter();
+                                match (inner.args.len(), args.next(), args.next()) {
+                                    (
+                                        2,
+                                        Some(syn::GenericArgument::Type(ok_type)),
+                                        Some(syn::GenericArgument::Type(err_type)),
+                                    ) => FieldType::Result_(ok_type.clone(), err_type.clone()),
+                                    _ => FieldType::Unhandled,
+                                }
                             } else {
-                                unreachable!()
+                                FieldType::Unhandled
                             }
                         }
                         _ => FieldType::Unhandled,
@@ -97,7 +338,179 @@ impl FieldType {
                 }
             }
             syn::Type::Array(type_array) => FieldType::Array(type_array.clone()),
+            // A tuple is `Copy` if all of its elements are known to be `Copy`.
+            syn::Type::Tuple(type_tuple) => {
+                let is_copy = type_tuple
+                    .elems
+                    .iter()
+                    .map(Self::from_type_without_hint)
+                    .collect::<ParseResult<Vec<_>>>()?
+                    .iter()
+                    .all(Self::is_copy);
+                if is_copy {
+                    FieldType::Copyable
+                } else {
+                    FieldType::Unhandled
+                }
+            }
+            syn::Type::Reference(type_reference) if type_reference.mutability.is_none() => {
+                FieldType::Reference
+            }
             _ => FieldType::Unhandled,
+        };
+        Ok(field_type)
+    }
+
+    /// Returns the initial value of the field, which is an empty or a zero value.
+    pub(crate) fn default_value(&self) -> proc_macro2::TokenStream {
+        match self {
+            FieldType::Boolean => quote!(false),
+            FieldType::Option_(_) => quote!(::core::option::Option::None),
+            _ => quote!(::core::default::Default::default()),
+        }
+    }
+
+    /// Returns the target type of the smart pointer.
+    pub(crate) fn pointer_target(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            FieldType::BoxedStr => Some(quote!(str)),
+            FieldType::BoxedSlice(type_slice) => Some(quote!(#type_slice)),
+            FieldType::Pointer(inner_type) | FieldType::Boxed(inner_type) => {
+                Some(quote!(#inner_type))
+            }
+            // Parenthesize the trait object, or `&dyn A + Send` is ambiguous.
+            FieldType::BoxedDyn(trait_object) if trait_object.bounds.len() > 1 => {
+                Some(quote!((#trait_object)))
+            }
+            FieldType::BoxedDyn(trait_object) => Some(quote!(#trait_object)),
+            _ => None,
+        }
+    }
+
+    /// Returns the target type of the smart pointer, or the `Deref` target of other types.
+    pub(crate) fn deref_target(&self, ty: &syn::Type) -> proc_macro2::TokenStream {
+        self.pointer_target()
+            .unwrap_or_else(|| quote!(<#ty as ::core::ops::Deref>::Target))
+    }
+
+    fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            FieldType::Number
+                | FieldType::Boolean
+                | FieldType::Character
+                | FieldType::Reference
+                | FieldType::Copyable
+        )
+    }
+
+    /// Checks whether the field is an array of `Copy` elements, and its length is a literal which
+    /// is not greater than the threshold.
+    fn is_small_copy_array(&self, threshold: Option<usize>) -> bool {
+        match (self, threshold) {
+            (FieldType::Array(type_array), Some(threshold)) => {
+                let len_opt = match type_array.len {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(ref lit),
+                        ..
+                    }) => Some(lit.value()),
+                    _ => None,
+                };
+                let is_copy = match Self::from_type_without_hint(&type_array.elem) {
+                    Ok(elem) => elem.is_copy(),
+                    Err(_) => false,
+                };
+                is_copy && len_opt.map_or(false, |len| len <= threshold as u64)
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn elem_type(&self) -> Option<syn::Type> {
+        match self {
+            FieldType::VecDeque(inner_type) => Some(inner_type.clone()),
+            _ => self.slice_type().map(|type_slice| *type_slice.elem),
+        }
+    }
+
+    /// Checks whether the type is any of the paths, a single name matches the last segment of the
+    /// type, while a longer path should match the whole path of the type.
+    pub(crate) fn is_one_of(ty: &syn::Type, paths: &[syn::Path]) -> bool {
+        if let syn::Type::Path(type_path) = ty {
+            let idents = type_path
+                .path
+                .segments
+                .iter()
+                .map(|seg| &seg.ident)
+                .collect::<Vec<_>>();
+            return paths.iter().any(|path| {
+                let path_idents = path.segments.iter().map(|seg| &seg.ident);
+                if path.segments.len() == 1 {
+                    path_idents.eq(idents.last().cloned())
+                } else {
+                    path_idents.eq(idents.iter().cloned())
+                }
+            });
+        }
+        false
+    }
+
+    /// Checks whether the field type could implement `Default`, i.e. it's not known not to.
+    pub(crate) fn may_be_default(&self, ty: &syn::Type) -> bool {
+        match (self, ty) {
+            (FieldType::Result_(..), _) | (FieldType::BoxedDyn(_), _) => false,
+            // Only the references to `str` and slices have default values.
+            (_, syn::Type::Reference(type_reference)) => match *type_reference.elem {
+                syn::Type::Slice(_) => true,
+                syn::Type::Path(ref type_path) => type_path.path.is_ident("str"),
+                _ => false,
+            },
+            (_, syn::Type::Ptr(_)) | (_, syn::Type::BareFn(_)) => false,
+            _ => true,
+        }
+    }
+
+    pub(crate) fn is_integer(ty: &syn::Type) -> bool {
+        if let syn::Type::Path(type_path) = ty {
+            if let Some(seg) = type_path.path.segments.iter().last() {
+                return matches!(
+                    seg.ident.to_string().as_ref(),
+                    "i8" | "i16"
+                        | "i32"
+                        | "i64"
+                        | "i128"
+                        | "isize"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "u128"
+                        | "usize"
+                );
+            }
+        }
+        false
+    }
+
+    pub(crate) fn slice_type(&self) -> Option<syn::TypeSlice> {
+        match self {
+            FieldType::Array(type_array) => {
+                let syn::TypeArray {
+                    bracket_token,
+                    elem,
+                    ..
+                } = type_array.clone();
+                Some(syn::TypeSlice {
+                    bracket_token,
+                    elem,
+                })
+            }
+            FieldType::Vector(inner_type) => Some(syn::TypeSlice {
+                bracket_token: syn::token::Bracket::default(),
+                elem: Box::new(inner_type.clone()),
+            }),
+            FieldType::BoxedSlice(type_slice) => Some(type_slice.clone()),
+            _ => None,
         }
     }
 }
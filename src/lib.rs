@@ -9,52 +9,506 @@
 extern crate proc_macro;
 
 use quote::quote;
+use syn::{ext::IdentExt as _, parse::Result as ParseResult, Error as SynError};
 
 mod generate;
 mod parse;
 
 use crate::{
-    generate::{FieldType, GetType},
-    parse::{FieldDef, GetTypeConf, PropertyDef, SetTypeConf},
+    generate::{FieldType, GetType, MethodKind, PredType},
+    parse::{
+        FieldDef, GetTypeConf, GroupByConf, InlineConf, MethodNameConf, MutTypeConf, PropertyDef,
+        PushTypeConf, RenameRuleConf, SetBoundConf, SetTypeConf, UpdateTypeConf,
+    },
 };
 
 /// Generate several common methods for structs automatically.
 #[proc_macro_derive(Property, attributes(property))]
 pub fn derive_property(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as PropertyDef);
-    let expanded = {
-        let PropertyDef {
-            name,
-            generics,
-            fields,
-        } = input;
-        let (impl_generics, type_generics, where_clause_opt) = generics.split_for_impl();
-        let methods = fields.into_iter().fold(Vec::new(), |mut r, f| {
-            r.append(&mut derive_property_for_field(f));
-            r
-        });
-        quote!(
+    let name = input.name.clone();
+    let generics = input.generics.clone();
+    let (impl_generics, type_generics, where_clause_opt) = generics.split_for_impl();
+    let expanded = match expand_property(input) {
+        Ok((methods, impls)) => quote!(
             impl #impl_generics #name #type_generics #where_clause_opt {
-                #(#[inline(always)] #methods)*
+                #(#methods)*
             }
-        )
+            #(#impls)*
+        ),
+        Err(err) => err.to_compile_error(),
+    };
+    expanded.into()
+}
+
+/// Generate the methods of `#[derive(Property)]` inside an existing `impl` block.
+///
+/// The argument is the declaration of the struct with its `#[property(...)]` attributes, it is
+/// only read to generate the methods, e.g. `#[property_methods(struct Pet { name: String })]`.
+#[proc_macro_attribute]
+pub fn property_methods(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(attr as PropertyDef);
+    let mut tokens = proc_macro2::TokenStream::from(item)
+        .into_iter()
+        .collect::<Vec<_>>();
+    let is_impl = tokens.iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == "impl",
+        _ => false,
+    });
+    let body = match tokens.pop() {
+        Some(proc_macro2::TokenTree::Group(ref group))
+            if is_impl && group.delimiter() == proc_macro2::Delimiter::Brace =>
+        {
+            group.clone()
+        }
+        _ => {
+            return SynError::new(
+                proc_macro2::Span::call_site(),
+                "`#[property_methods]` should be placed on an `impl` block",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let expanded = match expand_property(input) {
+        Ok((methods, impls)) => {
+            let items = body.stream();
+            let mut body_with_methods = proc_macro2::Group::new(
+                proc_macro2::Delimiter::Brace,
+                quote!(
+                    #items
+                    #(#methods)*
+                ),
+            );
+            body_with_methods.set_span(body.span());
+            quote!(
+                #(#tokens)* #body_with_methods
+                #(#impls)*
+            )
+        }
+        Err(err) => err.to_compile_error(),
     };
     expanded.into()
 }
 
-fn derive_property_for_field(field: FieldDef) -> Vec<proc_macro2::TokenStream> {
+/// Returns the methods in the inherent implementation, and the other implementations.
+fn expand_property(
+    input: PropertyDef,
+) -> ParseResult<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>)> {
+    let PropertyDef {
+        name,
+        generics,
+        fields,
+        conf,
+    } = input;
+    let (impl_generics, type_generics, where_clause_opt) = generics.split_for_impl();
+    let inline_attr = conf.inline.to_ts();
+    let mut impls = Vec::new();
+    if let Some(f) = fields.iter().find(|f| f.conf.as_ref) {
+        let FieldDef { ident, ty, .. } = f;
+        impls.push(quote!(
+            impl #impl_generics ::core::convert::AsRef<#ty> for #name #type_generics
+                #where_clause_opt
+            {
+                #[inline(always)]
+                fn as_ref(&self) -> &#ty {
+                    &self.#ident
+                }
+            }
+        ));
+    }
+    if let Some(f) = fields.iter().find(|f| f.conf.as_mut) {
+        let FieldDef { ident, ty, .. } = f;
+        impls.push(quote!(
+            impl #impl_generics ::core::convert::AsMut<#ty> for #name #type_generics
+                #where_clause_opt
+            {
+                #[inline(always)]
+                fn as_mut(&mut self) -> &mut #ty {
+                    &mut self.#ident
+                }
+            }
+        ));
+    }
+    if let Some(ref target) = conf.deref {
+        let f = fields
+            .iter()
+            .find(|f| f.ident.unraw() == target.unraw())
+            .expect("the field has been checked");
+        let FieldDef { ident, ty, .. } = f;
+        impls.push(quote!(
+            impl #impl_generics ::core::ops::Deref for #name #type_generics
+                #where_clause_opt
+            {
+                type Target = #ty;
+                #[inline(always)]
+                fn deref(&self) -> &#ty {
+                    &self.#ident
+                }
+            }
+        ));
+        if conf.deref_mut.is_some() {
+            impls.push(quote!(
+                impl #impl_generics ::core::ops::DerefMut for #name #type_generics
+                    #where_clause_opt
+                {
+                    #[inline(always)]
+                    fn deref_mut(&mut self) -> &mut #ty {
+                        &mut self.#ident
+                    }
+                }
+            ));
+        }
+    }
+    if conf.default {
+        let inits = fields
+            .iter()
+            .map(|f| {
+                let FieldDef {
+                    ident,
+                    ty,
+                    cfg_attrs,
+                    ..
+                } = f;
+                let value = FieldType::from_type(ty, f.conf.as_kind.as_ref())?.default_value();
+                Ok(quote!(#(#cfg_attrs)* #ident: #value))
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        impls.push(quote!(
+            impl #impl_generics ::core::default::Default for #name #type_generics
+                #where_clause_opt
+            {
+                fn default() -> Self {
+                    Self { #(#inits),* }
+                }
+            }
+        ));
+    }
+    let mut methods = Vec::new();
+    let mut method_names = Vec::new();
+    if let Some(visibility) = conf.dump.vis.to_ts() {
+        let method_name = &conf.dump.name;
+        method_names.push(method_name.unraw().to_string());
+        let pushes = fields.iter().filter(|f| !f.conf.skip).map(|f| {
+            let FieldDef {
+                ident, cfg_attrs, ..
+            } = f;
+            let field_str = ident.unraw().to_string();
+            // The value of a redacted field is replaced by its placeholder.
+            if f.conf.redact.vis.to_ts().is_some() {
+                let placeholder = &f.conf.redact.placeholder;
+                quote!(
+                    #(#cfg_attrs)*
+                    fields.push((#field_str, <::std::string::String as ::core::convert::From<&str>>::from(#placeholder)));
+                )
+            } else {
+                quote!(
+                    #(#cfg_attrs)*
+                    fields.push((#field_str, ::std::format!("{:?}", self.#ident)));
+                )
+            }
+        });
+        methods.push(quote!(
+            #inline_attr
+            #visibility fn #method_name(
+                &self,
+            ) -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+                let mut fields = ::std::vec::Vec::new();
+                #(#pushes)*
+                fields
+            }
+        ));
+    }
+    if let Some(visibility) = conf.new.vis.to_ts() {
+        let method_name = &conf.new.name;
+        method_names.push(method_name.unraw().to_string());
+        let params = fields.iter().filter(|f| !f.conf.skip).map(|f| {
+            let FieldDef {
+                ident,
+                ty,
+                cfg_attrs,
+                ..
+            } = f;
+            quote!(#(#cfg_attrs)* #ident: impl ::core::convert::Into<#ty>)
+        });
+        let inits = fields.iter().map(|f| {
+            let FieldDef {
+                ident, cfg_attrs, ..
+            } = f;
+            if f.conf.skip {
+                quote!(#(#cfg_attrs)* #ident: ::core::default::Default::default())
+            } else {
+                quote!(#(#cfg_attrs)* #ident: ::core::convert::Into::into(#ident))
+            }
+        });
+        methods.push(quote!(
+            #inline_attr
+            #visibility fn #method_name(#(#params),*) -> Self {
+                Self { #(#inits),* }
+            }
+        ));
+    }
+    for (vis, first, second) in conf.swaps.iter() {
+        let visibility = match vis.to_ts() {
+            Some(visibility) => visibility,
+            None => continue,
+        };
+        let method_name = syn::Ident::new(
+            &format!("swap_{}_{}", first.unraw(), second.unraw()),
+            first.span(),
+        );
+        method_names.push(method_name.to_string());
+        let find_field = |ident: &syn::Ident| {
+            fields
+                .iter()
+                .find(|f| f.ident.unraw() == ident.unraw())
+                .expect("the fields to swap have been checked")
+        };
+        let (first_field, second_field) = (find_field(first), find_field(second));
+        let (first, second) = (&first_field.ident, &second_field.ident);
+        let cfg_attrs = first_field
+            .cfg_attrs
+            .iter()
+            .chain(second_field.cfg_attrs.iter());
+        methods.push(quote!(
+            #(#cfg_attrs)*
+            #inline_attr
+            #visibility fn #method_name(&mut self) {
+                ::core::mem::swap(&mut self.#first, &mut self.#second);
+            }
+        ));
+    }
+    for computed in conf.computed.iter() {
+        if let Some(visibility) = computed.vis.to_ts() {
+            let method_name = &computed.name;
+            method_names.push(method_name.unraw().to_string());
+            let expr = &computed.expr;
+            let output = &computed.output;
+            methods.push(quote!(
+                #inline_attr
+                #visibility fn #method_name(&self) -> #output {
+                    #expr
+                }
+            ));
+        }
+    }
+    if conf.field_names {
+        for f in fields.iter().filter(|f| !f.conf.skip) {
+            let FieldDef {
+                ident, cfg_attrs, ..
+            } = f;
+            let field_str = ident.unraw().to_string();
+            let const_name =
+                syn::Ident::new(&format!("{}_FIELD", field_str.to_uppercase()), ident.span());
+            methods.push(quote!(
+                #(#cfg_attrs)*
+                pub const #const_name: &'static str = #field_str;
+            ));
+        }
+    }
+    // The renamed methods are not in snake case.
+    let allow_attr = if conf.rename_all.is_some() {
+        quote!(#[allow(non_snake_case)])
+    } else {
+        quote!()
+    };
+    let mut field_methods = Vec::new();
+    for f in fields.into_iter() {
+        let cfg_attrs = f.cfg_attrs.clone();
+        let deprecated_attr = f.deprecated_attr.clone();
+        let get_trait = f.conf.get.trait_.clone();
+        let set_trait = f.conf.set.trait_.clone();
+        let required = conf.require_accessors && !f.conf.skip;
+        let field_name = f.ident.clone();
+        match derive_property_for_field(
+            f,
+            conf.rename_all.as_ref(),
+            &conf.inline,
+            conf.array_copy_threshold,
+        ) {
+            Ok(ref ts) if required && ts.is_empty() => {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "this field has no accessors",
+                ));
+            }
+            Ok(ts) => {
+                field_methods.extend(ts.into_iter().map(|(kind, method_name, method)| {
+                    let cfg_attrs = cfg_attrs.iter();
+                    let field_str = field_name.unraw().to_string();
+                    let doc_alias_attr = if conf.doc_alias && method_name.unraw() != field_str {
+                        quote!(#[doc(alias = #field_str)])
+                    } else {
+                        quote!()
+                    };
+                    // The lints which are known to fire on the methods of each kind.
+                    let clippy_allow_attr = match kind {
+                        MethodKind::Set | MethodKind::Update | MethodKind::Push
+                            if conf.clippy_allow =>
+                        {
+                            quote!(#[allow(clippy::return_self_not_must_use)])
+                        }
+                        MethodKind::Pred if conf.clippy_allow => {
+                            quote!(#[allow(clippy::wrong_self_convention)])
+                        }
+                        _ => quote!(),
+                    };
+                    let trait_opt = match kind {
+                        MethodKind::Get => get_trait.clone(),
+                        MethodKind::Set => set_trait.clone(),
+                        _ => None,
+                    };
+                    // `#[deprecated]` is not allowed on the methods of a trait implementation.
+                    let deprecated_attr = if trait_opt.is_none() {
+                        deprecated_attr.clone()
+                    } else {
+                        None
+                    };
+                    let method = quote!(
+                        #(#cfg_attrs)*
+                        #deprecated_attr
+                        #allow_attr
+                        #clippy_allow_attr
+                        #doc_alias_attr
+                        #method
+                    );
+                    (kind, method_name, trait_opt, method)
+                }))
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    if let GroupByConf::Kind = conf.group_by {
+        // The sort is stable, so the methods of each kind keep the order of the fields.
+        field_methods.sort_by_key(|(kind, _, _, _)| *kind);
+    }
+    // The methods of each trait are put into their own implementation, in order of appearance.
+    let mut trait_methods: Vec<(String, syn::Path, Vec<proc_macro2::TokenStream>)> = Vec::new();
+    for (_, method_name, trait_opt, method) in field_methods.into_iter() {
+        method_names.push(method_name.unraw().to_string());
+        if let Some(path) = trait_opt {
+            let key = quote!(#path).to_string();
+            match trait_methods.iter_mut().find(|(k, _, _)| *k == key) {
+                Some((_, _, group)) => group.push(method),
+                None => trait_methods.push((key, path, vec![method])),
+            }
+        } else {
+            methods.push(method);
+        }
+    }
+    for (_, path, group) in trait_methods.into_iter() {
+        impls.push(quote!(
+            impl #impl_generics #path for #name #type_generics #where_clause_opt {
+                #(#group)*
+            }
+        ));
+    }
+    if conf.export_names {
+        methods.push(quote!(
+            #[doc(hidden)]
+            pub const _PROPERTY_METHODS: &'static [&'static str] = &[#(#method_names),*];
+        ));
+    }
+    Ok((methods, impls))
+}
+
+fn derive_property_for_field(
+    field: FieldDef,
+    rename_all: Option<&RenameRuleConf>,
+    inline: &InlineConf,
+    array_copy_threshold: Option<usize>,
+) -> ParseResult<Vec<(MethodKind, syn::Ident, proc_macro2::TokenStream)>> {
     let mut property = Vec::new();
+    if field.conf.skip {
+        return Ok(property);
+    }
     let field_type = &field.ty;
     let field_name = &field.ident;
+    let base_name = &field.base_name;
     let field_conf = &field.conf;
-    let prop_field_type = FieldType::from_type(field_type);
-    if let Some(ts) = field_conf.get.vis.to_ts().and_then(|visibility| {
-        let method_name = field_conf.get.name.complete(field_name);
+    let prop_field_type = FieldType::from_type(field_type, field_conf.as_kind.as_ref())?;
+    if let Some(visibility) = field_conf.get.vis.to_ts() {
+        // The methods in a trait implementation have no visibility.
+        let visibility = if field_conf.get.trait_.is_some() {
+            if field_conf.get.is_const {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "`const` getter can not be in a trait implementation",
+                ));
+            }
+            quote!()
+        } else {
+            visibility
+        };
+        let slice_type = || {
+            prop_field_type.slice_type().ok_or_else(|| {
+                SynError::new(
+                    field_name.span(),
+                    "`head` and `tail` getters only support Vec and array fields",
+                )
+            })
+        };
         let get_type = match field_conf.get.typ {
-            GetTypeConf::NotSet => GetType::from_field_type(&prop_field_type),
+            GetTypeConf::NotSet => GetType::from_field_type(&prop_field_type, array_copy_threshold),
             GetTypeConf::Ref => GetType::Ref,
             GetTypeConf::Copy_ => GetType::Copy_,
             GetTypeConf::Clone_ => GetType::Clone_,
+            GetTypeConf::Deref => GetType::Deref(prop_field_type.deref_target(field_type)),
+            GetTypeConf::Contains => match prop_field_type {
+                FieldType::Set(ref elem) => GetType::Contains(elem.clone()),
+                _ => {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "`contains` getters only support HashSet and BTreeSet fields",
+                    ));
+                }
+            },
+            GetTypeConf::Iter => GetType::iter(prop_field_type.elem_type().ok_or_else(|| {
+                SynError::new(
+                    field_name.span(),
+                    "`iter` getters only support Vec, VecDeque and array fields",
+                )
+            })?),
+            GetTypeConf::Head(count) => GetType::Head(slice_type()?, count),
+            GetTypeConf::Tail(count) => GetType::Tail(slice_type()?, count),
+            GetTypeConf::Map(ref func, ref output) => GetType::Map(func.clone(), output.clone()),
+            GetTypeConf::TryInto(ref output) => GetType::TryInto(output.clone()),
+            GetTypeConf::Borrow(ref output) => GetType::BorrowAs(output.clone()),
+            GetTypeConf::Ok_(ref err_opt) => GetType::Ok_(match err_opt {
+                Some(err) => quote!(#err),
+                None => quote!(::core::convert::Infallible),
+            }),
+        };
+        let method_name = match (&get_type, &field_conf.get.name) {
+            // A membership test reads better with a `has_` prefix.
+            (GetType::Contains(_), MethodNameConf::Format { prefix, suffix, .. })
+                if prefix.is_empty() && suffix.is_empty() =>
+            {
+                MethodNameConf::Format {
+                    prefix: "has_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                }
+                .complete(base_name, rename_all)?
+            }
+            _ => field_conf.get.name.complete(base_name, rename_all)?,
+        };
+        let constness = if field_conf.get.is_const {
+            match (&get_type, &prop_field_type) {
+                (GetType::Copy_, _) | (GetType::Slice(_), FieldType::Array(_)) => quote!(const),
+                _ => {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "`const` getter requires the getter to return by copy, \
+                         or to return a slice of an array",
+                    ));
+                }
+            }
+        } else {
+            quote!()
         };
         let generated = match get_type {
             GetType::Ref => quote!(
@@ -63,13 +517,13 @@ fn derive_property_for_field(field: FieldDef) -> Vec<proc_macro2::TokenStream> {
                 }
             ),
             GetType::Copy_ => quote!(
-                #visibility fn #method_name(&self) -> #field_type {
+                #visibility #constness fn #method_name(&self) -> #field_type {
                     self.#field_name
                 }
             ),
             GetType::Clone_ => quote!(
                 #visibility fn #method_name(&self) -> #field_type {
-                    self.#field_name.clone()
+                    ::core::clone::Clone::clone(&self.#field_name)
                 }
             ),
             GetType::String_ => quote!(
@@ -77,77 +531,1019 @@ fn derive_property_for_field(field: FieldDef) -> Vec<proc_macro2::TokenStream> {
                     &self.#field_name[..]
                 }
             ),
-            GetType::Slice(field_type) => quote!(
+            GetType::Path => quote!(
+                #visibility fn #method_name(&self) -> &::std::path::Path {
+                    self.#field_name.as_path()
+                }
+            ),
+            GetType::OsStr => quote!(
+                #visibility fn #method_name(&self) -> &::std::ffi::OsStr {
+                    self.#field_name.as_os_str()
+                }
+            ),
+            GetType::Slice(field_type) => {
+                if field_conf.get.is_const {
+                    // Indexing is not allowed in const fn, but the unsized coercion is.
+                    quote!(
+                        #visibility #constness fn #method_name(&self) -> &#field_type {
+                            &self.#field_name
+                        }
+                    )
+                } else {
+                    quote!(
+                        #visibility fn #method_name(&self) -> &#field_type {
+                            &self.#field_name[..]
+                        }
+                    )
+                }
+            }
+            GetType::Iter(elem) => quote!(
+                #visibility fn #method_name(&self) -> impl ::core::iter::Iterator<Item = &#elem> + '_ {
+                    self.#field_name.iter()
+                }
+            ),
+            GetType::Peek(elem) => quote!(
+                #visibility fn #method_name(&self) -> ::core::option::Option<&#elem> {
+                    self.#field_name.peek()
+                }
+            ),
+            GetType::IterDeref(target) => quote!(
+                #visibility fn #method_name(&self) -> impl ::core::iter::Iterator<Item = &#target> + '_ {
+                    ::core::iter::Iterator::map(self.#field_name.iter(), |elem| &**elem)
+                }
+            ),
+            GetType::Contains(elem) => quote!(
+                #visibility fn #method_name(&self, value: &#elem) -> bool {
+                    self.#field_name.contains(value)
+                }
+            ),
+            GetType::Head(field_type, count) => quote!(
                 #visibility fn #method_name(&self) -> &#field_type {
-                    &self.#field_name[..]
+                    let len = ::core::cmp::min(self.#field_name.len(), #count);
+                    &self.#field_name[..len]
+                }
+            ),
+            GetType::Tail(field_type, count) => quote!(
+                #visibility fn #method_name(&self) -> &#field_type {
+                    let len = self.#field_name.len();
+                    &self.#field_name[len - ::core::cmp::min(len, #count)..]
+                }
+            ),
+            GetType::Load(inner_type) => {
+                let ordering = field_conf.get.ordering.to_ts();
+                quote!(
+                    #visibility fn #method_name(&self) -> #inner_type {
+                        self.#field_name.load(#ordering)
+                    }
+                )
+            }
+            GetType::Borrow(inner_type) => quote!(
+                #visibility fn #method_name(&self) -> ::core::cell::Ref<'_, #inner_type> {
+                    self.#field_name.borrow()
+                }
+            ),
+            GetType::CellGet(inner_type) => quote!(
+                #visibility fn #method_name(&self) -> #inner_type {
+                    self.#field_name.get()
+                }
+            ),
+            GetType::Map(func, output) => quote!(
+                #visibility fn #method_name(&self) -> #output {
+                    #func(&self.#field_name)
+                }
+            ),
+            GetType::TryInto(output) => quote!(
+                #visibility fn #method_name(
+                    &self,
+                ) -> ::core::result::Result<
+                    #output,
+                    <#output as ::core::convert::TryFrom<#field_type>>::Error,
+                > {
+                    ::core::convert::TryFrom::try_from(::core::clone::Clone::clone(&self.#field_name))
+                }
+            ),
+            GetType::BorrowAs(output) => quote!(
+                #visibility fn #method_name(&self) -> &#output {
+                    ::core::borrow::Borrow::<#output>::borrow(&self.#field_name)
+                }
+            ),
+            GetType::Ok_(err) => quote!(
+                #visibility fn #method_name(&self) -> ::core::result::Result<&#field_type, #err> {
+                    ::core::result::Result::Ok(&self.#field_name)
+                }
+            ),
+            GetType::Deref(target) => quote!(
+                #visibility fn #method_name(&self) -> &#target {
+                    &*self.#field_name
+                }
+            ),
+            GetType::Result_(ok_type, err_type) => quote!(
+                #visibility fn #method_name(&self) -> ::core::result::Result<&#ok_type, &#err_type> {
+                    self.#field_name.as_ref()
+                }
+            ),
+            GetType::OptionDeref(target) => quote!(
+                #visibility fn #method_name(&self) -> ::core::option::Option<&#target> {
+                    self.#field_name.as_deref()
                 }
             ),
             GetType::Option_(field_type) => quote!(
-                #visibility fn #method_name(&self) -> Option<&#field_type> {
+                #visibility fn #method_name(&self) -> ::core::option::Option<&#field_type> {
                     self.#field_name.as_ref()
                 }
             ),
         };
-        Some(generated)
-    }) {
-        property.push(ts);
+        property.push((MethodKind::Get, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.to.vis.to_ts() {
+        let method_name = field_conf.to.name.complete(base_name, rename_all)?;
+        let generated = quote!(
+            #visibility fn #method_name(&self) -> #field_type {
+                ::core::clone::Clone::clone(&self.#field_name)
+            }
+        );
+        property.push((MethodKind::To, method_name, generated));
     }
-    if let Some(ts) = field_conf.set.vis.to_ts().and_then(|visibility| {
-        let method_name = field_conf.set.name.complete(field_name);
-        let generated = match prop_field_type {
-            FieldType::Vector(inner_type) => match field_conf.set.typ {
+    if let Some(visibility) = field_conf.set.vis.to_ts() {
+        let visibility = if field_conf.set.trait_.is_some() {
+            quote!()
+        } else {
+            visibility
+        };
+        let method_name = field_conf.set.name.complete(base_name, rename_all)?;
+        // Discarding the result of a setter, which takes `self`, discards the change.
+        let must_use_attr = if field_conf.set.must_use {
+            quote!(#[must_use])
+        } else {
+            quote!()
+        };
+        let interior = match &prop_field_type {
+            FieldType::Atomic(inner_type) => {
+                let ordering = field_conf.set.ordering.to_ts();
+                Some((
+                    inner_type.clone(),
+                    quote!(self.#field_name.store(val.into(), #ordering)),
+                    quote!(
+                        let old = self.#field_name.swap(val, #ordering);
+                        if old == val {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some(old)
+                        }
+                    ),
+                ))
+            }
+            FieldType::Cell(inner_type, _) => Some((
+                quote!(#inner_type),
+                quote!(self.#field_name.set(val.into())),
+                quote!(
+                    let old = self.#field_name.replace(val);
+                    if old == val {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(old)
+                    }
+                ),
+            )),
+            FieldType::RefCell(inner_type) => Some((
+                quote!(#inner_type),
+                quote!(*self.#field_name.borrow_mut() = val.into()),
+                quote!(
+                    let mut cur = self.#field_name.borrow_mut();
+                    if *cur == val {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(::core::mem::replace(&mut *cur, val))
+                    }
+                ),
+            )),
+            _ => None,
+        };
+        if field_conf.set.arg.is_none() && field_conf.set.convert.is_some() {
+            return Err(SynError::new(
+                field_name.span(),
+                "`convert` of `set` requires `arg`",
+            ));
+        }
+        let generated = if let Some((inner_type, store, swap)) = interior {
+            if field_conf.set.arg.is_some() {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "`arg` of `set` is not supported for atomic and cell fields",
+                ));
+            }
+            match field_conf.set.bound {
+                SetBoundConf::Into => {}
+                SetBoundConf::AsRef | SetBoundConf::Exact => {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "`as_ref` and `exact` bounds are not supported for atomic and cell fields",
+                    ));
+                }
+            }
+            match field_conf.set.typ {
                 SetTypeConf::Ref => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                       &mut self,
-                       val: impl IntoIterator<Item = T>
-                    ) -> &mut Self {
-                        self.#field_name = val.into_iter().map(Into::into).collect();
+                    #visibility fn #method_name<__PropVal: ::core::convert::Into<#inner_type>>(
+                        &self, val: __PropVal
+                    ) -> &Self {
+                        #store;
                         self
                     }
                 ),
                 SetTypeConf::Own => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        mut self,
-                        val: impl IntoIterator<Item = T>
+                    #must_use_attr
+                    #visibility fn #method_name<__PropVal: ::core::convert::Into<#inner_type>>(
+                        self, val: __PropVal
                     ) -> Self {
-                        self.#field_name = val.into_iter().map(Into::into).collect();
+                        #store;
                         self
                     }
                 ),
-            },
-            _ => match field_conf.set.typ {
+                // The old value of a `Cell` could not be compared after it is replaced.
+                SetTypeConf::ReplaceIfChanged
+                    if matches!(prop_field_type, FieldType::Cell(_, false)) =>
+                {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "the `replace_if_changed` type of `set` only supports `Cell` fields of \
+                         `Copy` types, which could be hinted by `as = \"copy\"`",
+                    ));
+                }
+                SetTypeConf::ReplaceIfChanged => quote!(
+                    #visibility fn #method_name<__PropVal: ::core::convert::Into<#inner_type>>(
+                        &self, val: __PropVal
+                    ) -> ::core::option::Option<#inner_type> {
+                        let val = val.into();
+                        #swap
+                    }
+                ),
+                SetTypeConf::CloneRef
+                | SetTypeConf::Extend
+                | SetTypeConf::TakeReplace
+                | SetTypeConf::FromSlice
+                | SetTypeConf::RefField
+                | SetTypeConf::Opt => {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "the `clone_ref`, `extend`, `take_replace`, `from_slice`, `ref_field` and \
+                         `opt` types of `set` are not supported for atomic and cell fields",
+                    ));
+                }
+            }
+        } else {
+            let (generics, input_type, convert) = match (&field_conf.set.bound, &prop_field_type) {
+                // The explicit argument type takes priority over the bound.
+                _ if field_conf.set.arg.is_some() => {
+                    let arg = &field_conf.set.arg;
+                    let convert = field_conf
+                        .set
+                        .convert
+                        .clone()
+                        .unwrap_or_else(|| quote!(::core::convert::Into::into(val)));
+                    (quote!(), quote!(#arg), convert)
+                }
+                (SetBoundConf::Into, _) => into_input(&prop_field_type, field_type),
+                (SetBoundConf::Exact, _) => (quote!(), quote!(#field_type), quote!(val)),
+                (SetBoundConf::AsRef, FieldType::String_) => (
+                    quote!(<__PropVal: ::core::convert::AsRef<str>>),
+                    quote!(__PropVal),
+                    quote!(::core::convert::Into::into(val.as_ref())),
+                ),
+                (SetBoundConf::AsRef, FieldType::Vector(inner_type)) => (
+                    quote!(<__PropVal: ::core::convert::AsRef<[#inner_type]>>),
+                    quote!(__PropVal),
+                    quote!(val.as_ref().to_vec()),
+                ),
+                (SetBoundConf::AsRef, _) => {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "`as_ref` bound only supports String and Vec fields",
+                    ));
+                }
+            };
+            match field_conf.set.typ {
                 SetTypeConf::Ref => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        &mut self, val: T
+                    #visibility fn #method_name #generics (
+                        &mut self, val: #input_type
                     ) -> &mut Self {
-                        self.#field_name = val.into();
+                        self.#field_name = #convert;
                         self
                     }
                 ),
                 SetTypeConf::Own => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        mut self, val: T
+                    #must_use_attr
+                    #visibility fn #method_name #generics (
+                        mut self, val: #input_type
                     ) -> Self {
-                        self.#field_name = val.into();
+                        self.#field_name = #convert;
                         self
                     }
                 ),
-            },
+                SetTypeConf::ReplaceIfChanged => quote!(
+                    #visibility fn #method_name #generics (
+                        &mut self, val: #input_type
+                    ) -> ::core::option::Option<#field_type> {
+                        let val: #field_type = #convert;
+                        if self.#field_name == val {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some(::core::mem::replace(&mut self.#field_name, val))
+                        }
+                    }
+                ),
+                SetTypeConf::RefField => quote!(
+                    #visibility fn #method_name #generics (
+                        &mut self, val: #input_type
+                    ) -> &mut #field_type {
+                        self.#field_name = #convert;
+                        &mut self.#field_name
+                    }
+                ),
+                SetTypeConf::CloneRef => quote!(
+                    #visibility fn #method_name(&mut self, val: &#field_type) -> &mut Self {
+                        self.#field_name = ::core::clone::Clone::clone(val);
+                        self
+                    }
+                ),
+                SetTypeConf::Extend => {
+                    let elem_type = match &prop_field_type {
+                        FieldType::Vector(inner_type)
+                        | FieldType::VecDeque(inner_type)
+                        | FieldType::Set(inner_type) => inner_type,
+                        _ => {
+                            return Err(SynError::new(
+                                field_name.span(),
+                                "the `extend` type of `set` only support Vec, VecDeque, \
+                                 HashSet and BTreeSet fields",
+                            ));
+                        }
+                    };
+                    quote!(
+                        #visibility fn #method_name<
+                            __PropVal: ::core::convert::Into<#elem_type>,
+                            __PropIter: ::core::iter::IntoIterator<Item = __PropVal>,
+                        >(
+                            &mut self, vals: __PropIter
+                        ) -> &mut Self {
+                            let vals = ::core::iter::IntoIterator::into_iter(vals);
+                            ::core::iter::Extend::extend(
+                                &mut self.#field_name,
+                                ::core::iter::Iterator::map(vals, ::core::convert::Into::into),
+                            );
+                            self
+                        }
+                    )
+                }
+                SetTypeConf::TakeReplace => {
+                    let inner_type = match &prop_field_type {
+                        FieldType::Option_(inner_type) => inner_type,
+                        _ => {
+                            return Err(SynError::new(
+                                field_name.span(),
+                                "the `take_replace` type of `set` only support Option fields",
+                            ));
+                        }
+                    };
+                    let (generics, input_type) = match field_conf.set.bound {
+                        SetBoundConf::Exact => (quote!(), quote!(#inner_type)),
+                        _ => (
+                            quote!(<__PropVal: ::core::convert::Into<#inner_type>>),
+                            quote!(__PropVal),
+                        ),
+                    };
+                    quote!(
+                        #visibility fn #method_name #generics (
+                            &mut self, val: #input_type
+                        ) -> #field_type {
+                            let old = self.#field_name.take();
+                            self.#field_name = ::core::option::Option::Some(val.into());
+                            old
+                        }
+                    )
+                }
+                SetTypeConf::Opt => {
+                    let inner_type = match &prop_field_type {
+                        FieldType::Option_(inner_type) => inner_type,
+                        _ => {
+                            return Err(SynError::new(
+                                field_name.span(),
+                                "the `opt` type of `set` only support Option fields",
+                            ));
+                        }
+                    };
+                    let (generics, input_type) = match field_conf.set.bound {
+                        SetBoundConf::Exact => (quote!(), quote!(#inner_type)),
+                        _ => (
+                            quote!(<__PropVal: ::core::convert::Into<#inner_type>>),
+                            quote!(__PropVal),
+                        ),
+                    };
+                    quote!(
+                        #visibility fn #method_name #generics (
+                            &mut self, val: ::core::option::Option<#input_type>
+                        ) -> &mut Self {
+                            self.#field_name = val.map(::core::convert::Into::into);
+                            self
+                        }
+                    )
+                }
+                SetTypeConf::FromSlice => {
+                    let elem_type = match &prop_field_type {
+                        FieldType::Array(type_array) => &type_array.elem,
+                        _ => {
+                            return Err(SynError::new(
+                                field_name.span(),
+                                "the `from_slice` type of `set` only support array fields",
+                            ));
+                        }
+                    };
+                    quote!(
+                        #visibility fn #method_name(
+                            &mut self, val: &[#elem_type]
+                        ) -> ::core::result::Result<(), ::core::array::TryFromSliceError> {
+                            self.#field_name = ::core::convert::TryInto::try_into(val)?;
+                            ::core::result::Result::Ok(())
+                        }
+                    )
+                }
+            }
         };
-        Some(generated)
-    }) {
-        property.push(ts);
+        property.push((MethodKind::Set, method_name, generated));
     }
-    if let Some(ts) = field_conf.mut_.vis.to_ts().and_then(|visibility| {
-        let method_name = field_conf.mut_.name.complete(field_name);
+    if let Some(visibility) = field_conf.replace.vis.to_ts() {
+        let method_name = field_conf.replace.name.complete(base_name, rename_all)?;
+        let (generics, input_type, convert) = into_input(&prop_field_type, field_type);
         let generated = quote!(
-            #visibility fn #method_name(&mut self) -> &mut #field_type {
-                &mut self.#field_name
+            #visibility fn #method_name #generics (&mut self, val: #input_type) -> #field_type {
+                ::core::mem::replace(&mut self.#field_name, #convert)
             }
         );
-        Some(generated)
+        property.push((MethodKind::Replace, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.mut_.vis.to_ts() {
+        let method_name = field_conf.mut_.name.complete(base_name, rename_all)?;
+        let generated = match (&field_conf.mut_.typ, &prop_field_type) {
+            (MutTypeConf::Option_, FieldType::Option_(inner_type))
+            | (MutTypeConf::Auto, FieldType::Option_(inner_type)) => quote!(
+                #visibility fn #method_name(&mut self) -> ::core::option::Option<&mut #inner_type> {
+                    self.#field_name.as_mut()
+                }
+            ),
+            (MutTypeConf::Option_, _) => {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "the `option` type of `mut` only support Option fields",
+                ));
+            }
+            (MutTypeConf::Slice, _) => {
+                if let Some(slice_type) = prop_field_type.slice_type() {
+                    quote!(
+                        #visibility fn #method_name(&mut self) -> &mut #slice_type {
+                            &mut self.#field_name[..]
+                        }
+                    )
+                } else {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "the `slice` type of `mut` only support Vec and array fields",
+                    ));
+                }
+            }
+            (MutTypeConf::Full, FieldType::BoxedDyn(_))
+            | (MutTypeConf::Auto, FieldType::BoxedDyn(_)) => {
+                let target = prop_field_type.deref_target(field_type);
+                quote!(
+                    #visibility fn #method_name(&mut self) -> &mut #target {
+                        &mut *self.#field_name
+                    }
+                )
+            }
+            (MutTypeConf::Full, FieldType::RefCell(inner_type))
+            | (MutTypeConf::Auto, FieldType::RefCell(inner_type)) => quote!(
+                #visibility fn #method_name(&self) -> ::core::cell::RefMut<'_, #inner_type> {
+                    self.#field_name.borrow_mut()
+                }
+            ),
+            (MutTypeConf::Full, _) | (MutTypeConf::Auto, _) => quote!(
+                #visibility fn #method_name(&mut self) -> &mut #field_type {
+                    &mut self.#field_name
+                }
+            ),
+        };
+        property.push((MethodKind::Mut, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.take.vis.to_ts() {
+        if prop_field_type.may_be_default(field_type) {
+            let method_name = field_conf.take.name.complete(base_name, rename_all)?;
+            let generated = quote!(
+                #visibility fn #method_name(&mut self) -> #field_type {
+                    ::core::mem::take(&mut self.#field_name)
+                }
+            );
+            property.push((MethodKind::Take, method_name, generated));
+        } else if field_conf.take.explicit {
+            return Err(SynError::new(
+                field_name.span(),
+                "`take` only support fields which implement `Default`",
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.pred.vis.to_ts() {
+        match PredType::from_field_type(&prop_field_type) {
+            Some(pred_type) => {
+                let method_name = match field_conf.pred.name {
+                    Some(ref name) => name.complete(base_name, rename_all)?,
+                    None => MethodNameConf::Format {
+                        prefix: pred_type.default_prefix().to_owned(),
+                        suffix: "".to_owned(),
+                        explicit: false,
+                    }
+                    .complete(base_name, rename_all)?,
+                };
+                let generated = match pred_type {
+                    PredType::Value => quote!(
+                        #visibility fn #method_name(&self) -> bool {
+                            self.#field_name
+                        }
+                    ),
+                    PredType::IsSome => quote!(
+                        #visibility fn #method_name(&self) -> bool {
+                            self.#field_name.is_some()
+                        }
+                    ),
+                    PredType::NotEmpty => quote!(
+                        #visibility fn #method_name(&self) -> bool {
+                            !self.#field_name.is_empty()
+                        }
+                    ),
+                };
+                property.push((MethodKind::Pred, method_name, generated));
+            }
+            None => {
+                if field_conf.pred.explicit {
+                    return Err(SynError::new(
+                        field_name.span(),
+                        "`pred` only support bool, Option, String and Vec fields",
+                    ));
+                }
+            }
+        }
+    }
+    // As `pred`, the `toggle`, `bits`, `push` and `at` methods enabled by the container attributes
+    // skip the fields which do not fit, while the field attributes require them to fit.
+    if let Some(visibility) = field_conf.toggle.vis.to_ts() {
+        if let FieldType::Boolean = prop_field_type {
+            let method_name = field_conf.toggle.name.complete(base_name, rename_all)?;
+            let generated = quote!(
+                #visibility fn #method_name(&mut self) {
+                    self.#field_name = !self.#field_name;
+                }
+            );
+            property.push((MethodKind::Toggle, method_name, generated));
+        } else {
+            if field_conf.toggle.explicit {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "`toggle` only support bool fields",
+                ));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf
+        .bits
+        .vis
+        .to_ts()
+        .filter(|_| field_conf.bits.explicit || FieldType::is_integer(field_type))
+    {
+        if !FieldType::is_integer(field_type) {
+            return Err(SynError::new(
+                field_name.span(),
+                "`bits` only support integer fields",
+            ));
+        }
+        // The default suffix keeps the names apart from the setter.
+        let (suffix, explicit) = match field_conf.bits.suffix {
+            Some(ref suffix) => (suffix.as_str(), true),
+            None => ("_bits", false),
+        };
+        let method_name = MethodNameConf::Format {
+            prefix: "set_".to_owned(),
+            suffix: suffix.to_owned(),
+            explicit,
+        }
+        .complete(base_name, rename_all)?;
+        let generated = quote!(
+            #visibility fn #method_name(&mut self, mask: #field_type) {
+                self.#field_name |= mask;
+            }
+        );
+        property.push((MethodKind::Bits, method_name, generated));
+        let method_name = MethodNameConf::Format {
+            prefix: "clear_".to_owned(),
+            suffix: suffix.to_owned(),
+            explicit,
+        }
+        .complete(base_name, rename_all)?;
+        let generated = quote!(
+            #visibility fn #method_name(&mut self, mask: #field_type) {
+                self.#field_name &= !mask;
+            }
+        );
+        property.push((MethodKind::Bits, method_name, generated));
+        let method_name = MethodNameConf::Format {
+            prefix: "has_".to_owned(),
+            suffix: suffix.to_owned(),
+            explicit,
+        }
+        .complete(base_name, rename_all)?;
+        let generated = quote!(
+            #visibility fn #method_name(&self, mask: #field_type) -> bool {
+                self.#field_name & mask == mask
+            }
+        );
+        property.push((MethodKind::Bits, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.update.vis.to_ts() {
+        let method_name = field_conf.update.name.complete(base_name, rename_all)?;
+        let generated = match field_conf.update.typ {
+            UpdateTypeConf::None_ => quote!(
+                #visibility fn #method_name<__PropFn: ::core::ops::FnOnce(&mut #field_type)>(
+                    &mut self, f: __PropFn
+                ) {
+                    f(&mut self.#field_name);
+                }
+            ),
+            UpdateTypeConf::Ref => quote!(
+                #visibility fn #method_name<__PropFn: ::core::ops::FnOnce(&mut #field_type)>(
+                    &mut self, f: __PropFn
+                ) -> &mut Self {
+                    f(&mut self.#field_name);
+                    self
+                }
+            ),
+        };
+        property.push((MethodKind::Update, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.push.vis.to_ts().filter(|_| {
+        field_conf.push.explicit
+            || matches!(
+                prop_field_type,
+                FieldType::Vector(_) | FieldType::VecDeque(_)
+            )
     }) {
-        property.push(ts);
+        let method_name = field_conf.push.name.complete(base_name, rename_all)?;
+        let (elem_type, push_method) = match &prop_field_type {
+            FieldType::Vector(inner_type) => (inner_type, quote!(push)),
+            FieldType::VecDeque(inner_type) => (inner_type, quote!(push_back)),
+            _ => {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "`push` only support Vec and VecDeque fields",
+                ));
+            }
+        };
+        let generated = match field_conf.push.typ {
+            PushTypeConf::None_ => quote!(
+                #visibility fn #method_name<__PropVal: ::core::convert::Into<#elem_type>>(
+                    &mut self, val: __PropVal
+                ) {
+                    self.#field_name.#push_method(val.into());
+                }
+            ),
+            PushTypeConf::Ref => quote!(
+                #visibility fn #method_name<__PropVal: ::core::convert::Into<#elem_type>>(
+                    &mut self, val: __PropVal
+                ) -> &mut Self {
+                    self.#field_name.#push_method(val.into());
+                    self
+                }
+            ),
+        };
+        property.push((MethodKind::Push, method_name, generated));
+    }
+    if let Some(visibility) = field_conf
+        .at
+        .vis
+        .to_ts()
+        .filter(|_| field_conf.at.explicit || prop_field_type.elem_type().is_some())
+    {
+        let method_name = field_conf.at.name.complete(base_name, rename_all)?;
+        let elem_type = prop_field_type.elem_type().ok_or_else(|| {
+            SynError::new(
+                field_name.span(),
+                "`at` only support Vec, VecDeque, boxed slice and array fields",
+            )
+        })?;
+        let generated = quote!(
+            #visibility fn #method_name(&self, idx: usize) -> ::core::option::Option<&#elem_type> {
+                self.#field_name.get(idx)
+            }
+        );
+        property.push((MethodKind::At, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.into.vis.to_ts() {
+        let method_name = field_conf.into.name.complete(base_name, rename_all)?;
+        let generated = quote!(
+            #visibility fn #method_name(self) -> #field_type {
+                self.#field_name
+            }
+        );
+        property.push((MethodKind::Into, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.redact.vis.to_ts() {
+        let method_name = field_conf.redact.name.complete(base_name, rename_all)?;
+        let placeholder = &field_conf.redact.placeholder;
+        let generated = quote!(
+            #visibility fn #method_name(&self) -> &'static str {
+                #placeholder
+            }
+        );
+        property.push((MethodKind::Redact, method_name, generated));
+    }
+    if let Some(visibility) = field_conf.forward.vis.to_ts() {
+        for method_name in field_conf.forward.methods.iter() {
+            let generated = match method_name.to_string().as_ref() {
+                "len" => quote!(
+                    #visibility fn #method_name(&self) -> usize {
+                        self.#field_name.len()
+                    }
+                ),
+                "is_empty" => quote!(
+                    #visibility fn #method_name(&self) -> bool {
+                        self.#field_name.is_empty()
+                    }
+                ),
+                "clear" => quote!(
+                    #visibility fn #method_name(&mut self) {
+                        self.#field_name.clear()
+                    }
+                ),
+                _ => unreachable!(),
+            };
+            property.push((MethodKind::Forward, method_name.clone(), generated));
+        }
+    }
+    for matches in field_conf.matches.iter() {
+        if let Some(visibility) = matches.vis.to_ts() {
+            let method_name = &matches.name;
+            let pattern = &matches.pattern;
+            let generated = quote!(
+                #visibility fn #method_name(&self) -> bool {
+                    match self.#field_name {
+                        #pattern => true,
+                        _ => false,
+                    }
+                }
+            );
+            property.push((MethodKind::Matches, method_name.clone(), generated));
+        }
+    }
+    let aliases = |kind: MethodKind| match kind {
+        MethodKind::Get => &field_conf.get.aliases[..],
+        MethodKind::Set => &field_conf.set.aliases[..],
+        MethodKind::Mut => &field_conf.mut_.aliases[..],
+        _ => &[],
+    };
+    if (!field_conf.get.aliases.is_empty() && field_conf.get.trait_.is_some())
+        || (!field_conf.set.aliases.is_empty() && field_conf.set.trait_.is_some())
+    {
+        return Err(SynError::new(
+            field_name.span(),
+            "`alias` can not be used with `trait`",
+        ));
+    }
+    // The aliases have the same bodies, and they are deprecated unless the field is.
+    let property = property
+        .into_iter()
+        .flat_map(|(kind, method_name, method)| {
+            let mut methods = Vec::new();
+            for alias in aliases(kind).iter() {
+                let note = format!("use `{}` instead", method_name.unraw());
+                let deprecated_attr = if field.deprecated_attr.is_none() {
+                    quote!(#[deprecated(note = #note)])
+                } else {
+                    quote!()
+                };
+                let alias_method = rename_method(&method, alias);
+                methods.push((
+                    kind,
+                    alias.clone(),
+                    quote!(
+                        #deprecated_attr
+                        #alias_method
+                    ),
+                ));
+            }
+            methods.insert(0, (kind, method_name, method));
+            methods
+        })
+        .map(|(kind, method_name, method)| {
+            let inline_opt = match kind {
+                MethodKind::Get => &field_conf.get.inline,
+                MethodKind::To => &field_conf.to.inline,
+                MethodKind::Set => &field_conf.set.inline,
+                MethodKind::Replace => &field_conf.replace.inline,
+                MethodKind::Mut => &field_conf.mut_.inline,
+                MethodKind::Take => &field_conf.take.inline,
+                MethodKind::Pred => &field_conf.pred.inline,
+                MethodKind::Toggle => &field_conf.toggle.inline,
+                MethodKind::Bits => &field_conf.bits.inline,
+                MethodKind::Update => &field_conf.update.inline,
+                MethodKind::Push => &field_conf.push.inline,
+                MethodKind::At => &field_conf.at.inline,
+                MethodKind::Into => &field_conf.into.inline,
+                MethodKind::Redact => &field_conf.redact.inline,
+                MethodKind::Forward | MethodKind::Matches => &None,
+            };
+            let inline_attr = inline_opt.as_ref().unwrap_or(inline).to_ts();
+            let method_attrs = field_conf
+                .method_attrs
+                .iter()
+                .filter(|(attr_kind, _)| *attr_kind == kind)
+                .map(|(_, attr)| attr);
+            let method = quote!(
+                #inline_attr
+                #(#method_attrs)*
+                #method
+            );
+            (kind, method_name, method)
+        })
+        .collect();
+    Ok(property)
+}
+
+/// Renames a generated method, i.e. replaces the identifier after the `fn` keyword.
+fn rename_method(method: &proc_macro2::TokenStream, name: &syn::Ident) -> proc_macro2::TokenStream {
+    let mut after_fn = false;
+    let mut renamed = false;
+    method
+        .clone()
+        .into_iter()
+        .map(|tt| match tt {
+            proc_macro2::TokenTree::Ident(_) if after_fn && !renamed => {
+                renamed = true;
+                proc_macro2::TokenTree::Ident(name.clone())
+            }
+            proc_macro2::TokenTree::Ident(ref ident) if ident == "fn" => {
+                after_fn = true;
+                tt
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+/// Returns the generics, the input type and the conversion expression for the methods which
+/// take a value and convert it into the field type via `Into`.
+fn into_input(
+    prop_field_type: &FieldType,
+    field_type: &syn::Type,
+) -> (
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+) {
+    match prop_field_type {
+        FieldType::Vector(inner_type) => (
+            quote!(<__PropVal: ::core::convert::Into<#inner_type>>),
+            quote!(impl ::core::iter::IntoIterator<Item = __PropVal>),
+            quote!(::core::iter::Iterator::collect(
+                ::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(val),
+                    ::core::convert::Into::into,
+                )
+            )),
+        ),
+        // A reference can not be converted from other types, so take it as is.
+        FieldType::Reference => (quote!(), quote!(#field_type), quote!(val)),
+        _ => (
+            quote!(<__PropVal: ::core::convert::Into<#field_type>>),
+            quote!(__PropVal),
+            quote!(val.into()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(input: proc_macro2::TokenStream) -> String {
+        let input = syn::parse2::<PropertyDef>(input).expect("the input should be a struct");
+        let (methods, impls) = expand_property(input).expect("the input should be expanded");
+        // The spacing of the tokens is not stable, so it is removed.
+        quote!(#(#methods)* #(#impls)*).to_string().replace(' ', "")
+    }
+
+    #[test]
+    fn doc_alias() {
+        let expanded = expand(quote!(
+            #[property(doc_alias)]
+            struct Pet {
+                #[property(get(name = "title"))]
+                name: String,
+                age: u8,
+            }
+        ));
+        // `set_name`, `mut_name` and `title`, while the getter `age` has the same name.
+        assert_eq!(expanded.matches(r#"#[doc(alias="name")]"#).count(), 3);
+        assert_eq!(expanded.matches(r#"#[doc(alias="age")]"#).count(), 2);
+        let expanded = expand(quote!(
+            struct Pet {
+                #[property(get(name = "title"))]
+                name: String,
+            }
+        ));
+        assert!(!expanded.contains("alias"));
+    }
+
+    #[test]
+    fn clippy_allow() {
+        let expanded = expand(quote!(
+            #[property(clippy_allow, get(disable), mut(disable), pred, update, push)]
+            struct Pet {
+                names: Vec<String>,
+                #[property(set(disable), update(disable), push(disable))]
+                hungry: bool,
+            }
+        ));
+        // `set_names`, `update_names` and `push_names`.
+        let attr = "#[allow(clippy::return_self_not_must_use)]";
+        assert_eq!(expanded.matches(attr).count(), 3);
+        // `has_names` and `is_hungry`.
+        let attr = "#[allow(clippy::wrong_self_convention)]";
+        assert_eq!(expanded.matches(attr).count(), 2);
+        let expanded = expand(quote!(
+            struct Pet {
+                #[property(pred)]
+                hungry: bool,
+            }
+        ));
+        assert!(!expanded.contains("clippy"));
+    }
+
+    #[test]
+    fn container_visibility() {
+        let expanded = expand(quote!(
+            #[property(visibility = "public")]
+            struct Pet {
+                name: String,
+                #[property(set(private))]
+                age: u8,
+            }
+        ));
+        assert_eq!(expanded.matches("pubfn").count(), 5);
+        assert!(!expanded.contains("pub(crate)"));
+        // The disabled methods stay disabled.
+        assert!(!expanded.contains("take_"));
+        assert!(expanded.contains("fnset_age") && !expanded.contains("pubfnset_age"));
+    }
+    #[test]
+    fn inline_bool() {
+        let expanded = expand(quote!(
+            #[property(inline = false)]
+            struct Pet {
+                #[property(get(inline = true), set(inline = false))]
+                name: String,
+            }
+        ));
+        // Only the getter is inlined.
+        assert_eq!(expanded.matches("#[inline").count(), 1);
+        assert!(expanded.contains("#[inline(always)]pub(crate)fnname"));
+    }
+
+    #[test]
+    fn inherited_visibility() {
+        // The container attribute, the field attribute, the field type and the number of methods.
+        let cases = [
+            ("push(public)", "push", "Vec<String>", 1),
+            ("push(public)", r#"push(name = "add")"#, "Vec<String>", 1),
+            ("redact(public)", "redact", "String", 1),
+            (
+                "redact(public)",
+                r#"redact(placeholder = "*")"#,
+                "String",
+                1,
+            ),
+            ("redact(public)", r#"redact = "****""#, "String", 1),
+            ("bits(public)", "bits", "u8", 3),
+            ("bits(public)", r#"bits(inline = "always")"#, "u8", 3),
+            ("at(public)", "at", "Vec<String>", 1),
+            ("into(public)", "into", "Vec<String>", 1),
+        ];
+        for (container_attr, field_attr, field_type, count) in cases.iter() {
+            let parse = |s: &str| s.parse::<proc_macro2::TokenStream>().unwrap();
+            let (container_attr, field_attr) = (parse(container_attr), parse(field_attr));
+            let field_type = parse(field_type);
+            let expanded = expand(quote!(
+                #[property(get(disable), set(disable), mut(disable), #container_attr)]
+                struct Pet {
+                    #[property(#field_attr)]
+                    names: #field_type,
+                }
+            ));
+            assert_eq!(expanded.matches("pubfn").count(), *count, "{}", field_attr);
+            assert!(!expanded.contains("pub(crate)"), "{}", field_attr);
+        }
     }
-    property
 }
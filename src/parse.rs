@@ -7,26 +7,118 @@
 // except according to those terms.
 
 use quote::quote;
-use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+use syn::{ext::IdentExt as _, parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+use unicode_xid::UnicodeXID as _;
+
+use crate::generate::{FieldType, MethodKind};
 
 const ATTR_NAME: &str = "property";
+const NEW_KEYWORDS: &[&str] = &["async", "await", "dyn", "try", "gen"];
 
-const GET_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["ref", "copy", "clone"]));
-const SET_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["ref", "own"]));
+const GET_TYPE_OPTIONS: (&str, Option<&[&str]>) = (
+    "type",
+    Some(&[
+        "ref", "copy", "clone", "iter", "head", "tail", "try_into", "contains", "deref", "ok",
+        "borrow",
+    ]),
+);
+const GET_COUNT_OPTION: (&str, Option<&[&str]>) = ("n", None);
+const GET_MAP_OPTION: (&str, Option<&[&str]>) = ("map", None);
+const GET_TYPE_OUT_OPTION: (&str, Option<&[&str]>) = ("type_out", None);
+const GET_ERR_OPTION: (&str, Option<&[&str]>) = ("err", None);
+const GET_ORDERING_OPTIONS: (&str, Option<&[&str]>) =
+    ("ordering", Some(&["relaxed", "acquire", "seq_cst"]));
+const SET_ORDERING_OPTIONS: (&str, Option<&[&str]>) =
+    ("ordering", Some(&["relaxed", "release", "seq_cst"]));
+const SET_TYPE_OPTIONS: (&str, Option<&[&str]>) = (
+    "type",
+    Some(&[
+        "ref",
+        "own",
+        "replace_if_changed",
+        "clone_ref",
+        "extend",
+        "take_replace",
+        "from_slice",
+        "ref_field",
+        "opt",
+    ]),
+);
+const SET_BOUND_OPTIONS: (&str, Option<&[&str]>) = ("bound", Some(&["into", "as_ref", "exact"]));
+const SET_MUST_USE_OPTIONS: (&str, Option<&[&str]>) = ("must_use", Some(&["true", "false"]));
+const SET_ARG_OPTION: (&str, Option<&[&str]>) = ("arg", None);
+const SET_CONVERT_OPTION: (&str, Option<&[&str]>) = ("convert", None);
+const MUT_TYPE_OPTIONS: (&str, Option<&[&str]>) =
+    ("type", Some(&["full", "option", "slice", "auto"]));
+const UPDATE_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["none", "ref"]));
+const PUSH_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["none", "ref"]));
+const INLINE_OPTIONS: (&str, Option<&[&str]>) =
+    ("inline", Some(&["always", "default", "never", "none"]));
+const TRAIT_OPTION: (&str, Option<&[&str]>) = ("trait", None);
 const NAME_OPTION: (&str, Option<&[&str]>) = ("name", None);
+const ALIAS_OPTION: (&str, Option<&[&str]>) = ("alias", None);
+const ATTR_OPTION: (&str, Option<&[&str]>) = ("attr", None);
+const CFG_OPTION: (&str, Option<&[&str]>) = ("cfg", None);
+const PATTERN_OPTION: (&str, Option<&[&str]>) = ("pattern", None);
+const EXPR_OPTION: (&str, Option<&[&str]>) = ("expr", None);
+const PLACEHOLDER_OPTION: (&str, Option<&[&str]>) = ("placeholder", None);
 const PREFIX_OPTION: (&str, Option<&[&str]>) = ("prefix", None);
 const SUFFIX_OPTION: (&str, Option<&[&str]>) = ("suffix", None);
 const VISIBILITY_OPTIONS: &[&str] = &["disable", "public", "crate", "private"];
+const CONST_OPTIONS: &[&str] = &["const"];
+const FORWARD_METHODS: &[&str] = &["len", "is_empty", "clear"];
+const GET_COPY_OPTIONS: &[&str] = &["copy"];
 
 pub(crate) struct PropertyDef {
     pub(crate) name: syn::Ident,
     pub(crate) generics: syn::Generics,
     pub(crate) fields: Vec<FieldDef>,
+    pub(crate) conf: ContainerConf,
+}
+
+pub(crate) struct ContainerConf {
+    pub(crate) skip_prefix: Option<String>,
+    pub(crate) prefix: Option<String>,
+    pub(crate) readonly: bool,
+    pub(crate) skip_all: bool,
+    pub(crate) export_names: bool,
+    pub(crate) field_names: bool,
+    pub(crate) skip_pub_fields: bool,
+    pub(crate) default: bool,
+    pub(crate) require_accessors: bool,
+    pub(crate) doc_alias: bool,
+    pub(crate) clippy_allow: bool,
+    pub(crate) array_copy_threshold: Option<usize>,
+    pub(crate) copy_types: Vec<syn::Path>,
+    pub(crate) rename_all: Option<RenameRuleConf>,
+    pub(crate) inline: InlineConf,
+    pub(crate) group_by: GroupByConf,
+    pub(crate) dump: ContainerMethodConf,
+    pub(crate) new: ContainerMethodConf,
+    pub(crate) swaps: Vec<(VisibilityConf, syn::Ident, syn::Ident)>,
+    pub(crate) computed: Vec<ComputedConf>,
+    pub(crate) deref: Option<syn::Ident>,
+    pub(crate) deref_mut: Option<syn::Ident>,
+}
+
+pub(crate) struct ComputedConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+    pub(crate) expr: proc_macro2::TokenStream,
+    pub(crate) output: syn::Type,
+}
+
+pub(crate) struct ContainerMethodConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
 }
 
 pub(crate) struct FieldDef {
     pub(crate) ident: syn::Ident,
+    pub(crate) base_name: syn::Ident,
     pub(crate) ty: syn::Type,
+    pub(crate) cfg_attrs: Vec<syn::Attribute>,
+    pub(crate) deprecated_attr: Option<syn::Attribute>,
     pub(crate) conf: FieldConf,
 }
 
@@ -36,12 +128,84 @@ pub(crate) enum GetTypeConf {
     Ref,
     Copy_,
     Clone_,
+    Iter,
+    Contains,
+    Deref,
+    Head(usize),
+    Tail(usize),
+    Map(syn::Path, syn::Type),
+    TryInto(syn::Type),
+    Borrow(syn::Type),
+    Ok_(Option<syn::Type>),
 }
 
 #[derive(Clone)]
 pub(crate) enum SetTypeConf {
     Ref,
     Own,
+    ReplaceIfChanged,
+    CloneRef,
+    Extend,
+    TakeReplace,
+    FromSlice,
+    RefField,
+    Opt,
+}
+
+#[derive(Clone)]
+pub(crate) enum SetBoundConf {
+    Into,
+    AsRef,
+    Exact,
+}
+
+#[derive(Clone)]
+pub(crate) enum OrderingConf {
+    Relaxed,
+    Acquire,
+    Release,
+    SeqCst,
+}
+
+#[derive(Clone)]
+pub(crate) enum MutTypeConf {
+    Full,
+    Option_,
+    Slice,
+    Auto,
+}
+
+#[derive(Clone)]
+pub(crate) enum UpdateTypeConf {
+    None_,
+    Ref,
+}
+
+#[derive(Clone)]
+pub(crate) enum PushTypeConf {
+    None_,
+    Ref,
+}
+
+#[derive(Clone)]
+pub(crate) enum GroupByConf {
+    Field,
+    Kind,
+}
+
+#[derive(Clone)]
+pub(crate) enum InlineConf {
+    Always,
+    Default,
+    Never,
+    None_,
+}
+
+#[derive(Clone)]
+pub(crate) enum RenameRuleConf {
+    Camel,
+    Pascal,
+    ScreamingSnake,
 }
 
 #[derive(Clone)]
@@ -52,37 +216,183 @@ pub(crate) enum VisibilityConf {
     Private,
 }
 
+#[derive(Clone)]
+pub(crate) enum FieldTypeHint {
+    Number,
+    Copy_,
+    Clone_,
+}
+
 #[derive(Clone)]
 pub(crate) enum MethodNameConf {
     Name(String),
-    Format { prefix: String, suffix: String },
+    /// The `explicit` flag is set if the prefix or the suffix was set by the attributes.
+    Format {
+        prefix: String,
+        suffix: String,
+        explicit: bool,
+    },
 }
 
 #[derive(Clone)]
 pub(crate) struct GetFieldConf {
     pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
     pub(crate) name: MethodNameConf,
     pub(crate) typ: GetTypeConf,
+    pub(crate) is_const: bool,
+    pub(crate) ordering: OrderingConf,
+    pub(crate) trait_: Option<syn::Path>,
+    pub(crate) aliases: Vec<syn::Ident>,
 }
 
 #[derive(Clone)]
 pub(crate) struct SetFieldConf {
     pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
     pub(crate) name: MethodNameConf,
     pub(crate) typ: SetTypeConf,
+    pub(crate) bound: SetBoundConf,
+    pub(crate) ordering: OrderingConf,
+    pub(crate) must_use: bool,
+    pub(crate) arg: Option<syn::Type>,
+    pub(crate) convert: Option<proc_macro2::TokenStream>,
+    pub(crate) trait_: Option<syn::Path>,
+    pub(crate) aliases: Vec<syn::Ident>,
 }
 
 #[derive(Clone)]
 pub(crate) struct MutFieldConf {
     pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) typ: MutTypeConf,
+    pub(crate) aliases: Vec<syn::Ident>,
+}
+
+#[derive(Clone)]
+pub(crate) struct TakeFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) explicit: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct UpdateFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) typ: UpdateTypeConf,
+}
+
+#[derive(Clone)]
+pub(crate) struct PushFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) typ: PushTypeConf,
+    pub(crate) explicit: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct AtFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) explicit: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct IntoFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+}
+
+#[derive(Clone)]
+pub(crate) struct RedactFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+    pub(crate) placeholder: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct ForwardFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) methods: Vec<syn::Ident>,
+}
+
+#[derive(Clone)]
+pub(crate) struct MatchesFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+    pub(crate) pattern: proc_macro2::TokenStream,
+}
+
+#[derive(Clone)]
+pub(crate) struct ToFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+}
+
+#[derive(Clone)]
+pub(crate) struct ReplaceFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: MethodNameConf,
+}
+
+#[derive(Clone)]
+pub(crate) struct BitsFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) suffix: Option<String>,
+    pub(crate) explicit: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct ToggleFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
     pub(crate) name: MethodNameConf,
+    pub(crate) explicit: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct PredFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) inline: Option<InlineConf>,
+    pub(crate) name: Option<MethodNameConf>,
+    pub(crate) explicit: bool,
 }
 
 #[derive(Clone)]
 pub(crate) struct FieldConf {
     pub(crate) get: GetFieldConf,
+    pub(crate) to: ToFieldConf,
     pub(crate) set: SetFieldConf,
     pub(crate) mut_: MutFieldConf,
+    pub(crate) take: TakeFieldConf,
+    pub(crate) replace: ReplaceFieldConf,
+    pub(crate) pred: PredFieldConf,
+    pub(crate) toggle: ToggleFieldConf,
+    pub(crate) update: UpdateFieldConf,
+    pub(crate) push: PushFieldConf,
+    pub(crate) at: AtFieldConf,
+    pub(crate) into: IntoFieldConf,
+    pub(crate) redact: RedactFieldConf,
+    pub(crate) forward: ForwardFieldConf,
+    pub(crate) matches: Vec<MatchesFieldConf>,
+    pub(crate) bits: BitsFieldConf,
+    pub(crate) as_kind: Option<FieldTypeHint>,
+    pub(crate) rename: Option<syn::Ident>,
+    pub(crate) as_ref: bool,
+    pub(crate) as_mut: bool,
+    pub(crate) skip: bool,
+    pub(crate) method_attrs: Vec<(MethodKind, syn::Attribute)>,
 }
 
 impl syn::parse::Parse for PropertyDef {
@@ -96,55 +406,251 @@ impl syn::parse::Parse for PropertyDef {
             data,
             ..
         } = derive_input;
-        let conf = Self::parse_attrs(span, &attrs[..])?;
+        let (container_conf, conf) = Self::parse_attrs(span, &attrs[..])?;
+        if conf.as_ref || conf.as_mut {
+            Err(SynError::new(
+                span,
+                "`as_ref` and `as_mut` are only allowed on fields",
+            ))?;
+        }
+        let fields = FieldDef::parse_data(data, &container_conf, conf, span)?;
+        if let Some(f) = fields.iter().filter(|f| f.conf.as_ref).nth(1) {
+            Err(SynError::new(
+                f.ident.span(),
+                "`as_ref` is only allowed on one field",
+            ))?;
+        }
+        if let Some(f) = fields.iter().filter(|f| f.conf.as_mut).nth(1) {
+            Err(SynError::new(
+                f.ident.span(),
+                "`as_mut` is only allowed on one field",
+            ))?;
+        }
+        for (_, first, second) in container_conf.swaps.iter() {
+            let find_field = |ident: &syn::Ident| {
+                fields
+                    .iter()
+                    .find(|f| f.ident.unraw() == ident.unraw())
+                    .ok_or_else(|| SynError::new(ident.span(), "this field was unknown"))
+            };
+            let first_field = find_field(first)?;
+            let second_field = find_field(second)?;
+            let (first_ty, second_ty) = (&first_field.ty, &second_field.ty);
+            if quote!(#first_ty).to_string() != quote!(#second_ty).to_string() {
+                Err(SynError::new(
+                    second.span(),
+                    "the fields to swap should have the same type",
+                ))?;
+            }
+        }
+        if let Some(ref target) = container_conf.deref {
+            if !fields.iter().any(|f| f.ident.unraw() == target.unraw()) {
+                Err(SynError::new(target.span(), "this field was unknown"))?;
+            }
+        }
+        if let Some(ref target) = container_conf.deref_mut {
+            // `DerefMut` reuses the target of `Deref`.
+            match container_conf.deref {
+                Some(ref deref) if deref.unraw() == target.unraw() => {}
+                _ => Err(SynError::new(
+                    target.span(),
+                    "`deref_mut` requires `deref` on the same field",
+                ))?,
+            }
+        }
         Ok(Self {
             name: ident,
             generics,
-            fields: FieldDef::parse_data(data, conf, span)?,
+            fields,
+            conf: container_conf,
         })
     }
 }
 
 impl PropertyDef {
-    fn parse_attrs(span: proc_macro2::Span, attrs: &[syn::Attribute]) -> ParseResult<FieldConf> {
-        Ok(parse_attrs(span, Default::default(), attrs)?)
+    fn parse_attrs(
+        span: proc_macro2::Span,
+        attrs: &[syn::Attribute],
+    ) -> ParseResult<(ContainerConf, FieldConf)> {
+        let mut container_conf = ContainerConf::default();
+        let mut conf = FieldConf::default();
+        let metas = parse_attrs(span, attrs)?;
+        // The default visibility, the presets and the naming defaults are applied first, so the
+        // other attributes override them.
+        for meta in metas.iter() {
+            if let syn::Meta::NameValue(mnv) = meta {
+                if mnv.ident == "visibility" {
+                    conf.apply_visibility(mnv)?;
+                }
+            }
+        }
+        for meta in metas.iter() {
+            if let syn::Meta::NameValue(mnv) = meta {
+                if mnv.ident == "preset" {
+                    conf.apply_preset(mnv)?;
+                }
+            }
+        }
+        for meta in metas.iter() {
+            if let syn::Meta::List(list) = meta {
+                if list.ident == "accessor_prefix" {
+                    conf.apply_accessor_prefix(list)?;
+                }
+            }
+        }
+        for meta in metas.iter() {
+            if !container_conf.apply_attrs(meta)? {
+                conf.apply_attrs(meta)?;
+            }
+        }
+        if let Some(ref name) = conf.rename {
+            Err(SynError::new(
+                name.span(),
+                "`rename` is only a field attribute",
+            ))?;
+        }
+        if container_conf.readonly {
+            conf.set.vis = VisibilityConf::Disable;
+            conf.mut_.vis = VisibilityConf::Disable;
+        }
+        if container_conf.skip_all {
+            conf.get.vis = VisibilityConf::Disable;
+            conf.to.vis = VisibilityConf::Disable;
+            conf.set.vis = VisibilityConf::Disable;
+            conf.mut_.vis = VisibilityConf::Disable;
+            conf.take.vis = VisibilityConf::Disable;
+            conf.replace.vis = VisibilityConf::Disable;
+            conf.pred.vis = VisibilityConf::Disable;
+            conf.toggle.vis = VisibilityConf::Disable;
+            conf.update.vis = VisibilityConf::Disable;
+            conf.push.vis = VisibilityConf::Disable;
+            conf.at.vis = VisibilityConf::Disable;
+            conf.into.vis = VisibilityConf::Disable;
+            conf.redact.vis = VisibilityConf::Disable;
+            conf.forward.methods.clear();
+            conf.matches.clear();
+            conf.bits.vis = VisibilityConf::Disable;
+        }
+        Ok((container_conf, conf))
     }
 }
 
 impl FieldDef {
     fn parse_data(
         data: syn::Data,
+        container_conf: &ContainerConf,
         conf: FieldConf,
         span: proc_macro2::Span,
     ) -> ParseResult<Vec<Self>> {
         match data {
             syn::Data::Struct(data) => {
+                match data.fields {
+                    syn::Fields::Named(_) => {}
+                    syn::Fields::Unnamed(ref fields) => Err(SynError::new(
+                        fields.span(),
+                        "`#[derive(Property)]` expected a struct with named fields, found a tuple struct",
+                    ))?,
+                    syn::Fields::Unit => Err(SynError::new(
+                        data.semi_token.map(|token| token.span()).unwrap_or(span),
+                        "`#[derive(Property)]` expected a struct with named fields, found a unit struct",
+                    ))?,
+                }
                 let mut fields = Vec::new();
                 for f in data.fields.into_iter() {
                     let syn::Field {
-                        attrs, ident, ty, ..
+                        attrs,
+                        ident,
+                        ty,
+                        vis,
+                        ..
                     } = f.clone();
-                    let conf = Self::parse_attrs(f.span(), conf.clone(), &attrs[..])?;
+                    let mut conf = conf.clone();
+                    // The visible fields can be read directly, unless the getter is set.
+                    if container_conf.skip_pub_fields && !matches!(vis, syn::Visibility::Inherited)
+                    {
+                        conf.get.vis = VisibilityConf::Disable;
+                    }
+                    let mut conf = Self::parse_attrs(f.span(), conf, &attrs[..])?;
                     let ident =
                         ident.ok_or_else(|| SynError::new(f.span(), "only support named field"))?;
-                    let field = Self { ident, ty, conf };
+                    if let Some(ref prefix) = container_conf.skip_prefix {
+                        if ident.unraw().to_string().starts_with(prefix) {
+                            conf.skip = true;
+                        }
+                    }
+                    if conf.as_kind.is_none()
+                        && FieldType::is_one_of(&ty, &container_conf.copy_types)
+                    {
+                        conf.as_kind = Some(FieldTypeHint::Copy_);
+                    }
+                    // Marker fields are skipped, unless they are configured explicitly.
+                    if let FieldType::Phantom = FieldType::from_type(&ty, None)? {
+                        if parse_attrs(f.span(), &attrs[..])?.is_empty() {
+                            conf.skip = true;
+                        }
+                    }
+                    // The new name replaces the field name, and the prefix is not prepended.
+                    let base_name = match (&conf.rename, &container_conf.prefix) {
+                        (Some(name), _) => name.clone(),
+                        (None, Some(prefix)) => {
+                            parse_str::<syn::Ident>(&format!("{}{}", prefix, ident.unraw()))
+                                .map(|name| syn::Ident::new(&name.to_string(), ident.span()))
+                                .map_err(|_| {
+                                    SynError::new(
+                                        ident.span(),
+                                        "the prefix makes an invalid name for this field",
+                                    )
+                                })?
+                        }
+                        (None, None) => ident.clone(),
+                    };
+                    let cfg_attrs = attrs
+                        .iter()
+                        .filter(|attr| attr.path.is_ident("cfg"))
+                        .cloned()
+                        .collect();
+                    let deprecated_attr = attrs
+                        .iter()
+                        .find(|attr| attr.path.is_ident("deprecated"))
+                        .cloned();
+                    let field = Self {
+                        ident,
+                        base_name,
+                        ty,
+                        cfg_attrs,
+                        deprecated_attr,
+                        conf,
+                    };
                     fields.push(field);
                 }
                 Ok(fields)
             }
-            _ => Err(SynError::new(
-                span,
-                "`#[derive(Property)]` only support structs",
+            syn::Data::Enum(data) => Err(SynError::new(
+                data.enum_token.span(),
+                "`#[derive(Property)]` expected a struct, found an enum",
+            )),
+            syn::Data::Union(data) => Err(SynError::new(
+                data.union_token.span(),
+                "`#[derive(Property)]` expected a struct, found a union",
             )),
         }
     }
 
     fn parse_attrs(
         span: proc_macro2::Span,
-        conf: FieldConf,
+        mut conf: FieldConf,
         attrs: &[syn::Attribute],
     ) -> ParseResult<FieldConf> {
-        Ok(parse_attrs(span, conf, attrs)?)
+        conf.pred.explicit = false;
+        conf.take.explicit = false;
+        conf.toggle.explicit = false;
+        conf.bits.explicit = false;
+        conf.push.explicit = false;
+        conf.at.explicit = false;
+        for meta in parse_attrs(span, attrs)?.iter() {
+            conf.apply_attrs(meta)?;
+        }
+        Ok(conf)
     }
 }
 
@@ -153,13 +659,104 @@ impl GetTypeConf {
         namevalue_params: &::std::collections::HashMap<&str, String>,
         span: proc_macro2::Span,
     ) -> ParseResult<Option<Self>> {
+        let count_opt = match namevalue_params.get("n") {
+            None => None,
+            Some(input) => Some(
+                input
+                    .parse::<usize>()
+                    .map_err(|_| SynError::new(span, "`n` should be an unsigned integer"))?,
+            ),
+        };
+        let output_opt = match namevalue_params.get("type_out") {
+            None => None,
+            Some(input) => Some(
+                parse_str::<syn::Type>(input)
+                    .map_err(|_| SynError::new(span, "`type_out` should be a type"))?,
+            ),
+        };
+        let err_opt = match namevalue_params.get("err") {
+            None => None,
+            Some(input) => Some(
+                parse_str::<syn::Type>(input)
+                    .map_err(|_| SynError::new(span, "`err` should be a type"))?,
+            ),
+        };
         let choice = match namevalue_params.get("type").map(AsRef::as_ref) {
             None => None,
             Some("ref") => Some(GetTypeConf::Ref),
             Some("copy") => Some(GetTypeConf::Copy_),
             Some("clone") => Some(GetTypeConf::Clone_),
+            Some("iter") => Some(GetTypeConf::Iter),
+            Some("contains") => Some(GetTypeConf::Contains),
+            Some("deref") => Some(GetTypeConf::Deref),
+            Some("head") | Some("tail") => {
+                let count = count_opt.ok_or_else(|| {
+                    SynError::new(span, "`n` is required for the `head` and `tail` types")
+                })?;
+                if namevalue_params["type"] == "head" {
+                    Some(GetTypeConf::Head(count))
+                } else {
+                    Some(GetTypeConf::Tail(count))
+                }
+            }
+            Some("try_into") => {
+                let output = output_opt.clone().ok_or_else(|| {
+                    SynError::new(span, "`type_out` is required for the `try_into` type")
+                })?;
+                Some(GetTypeConf::TryInto(output))
+            }
+            Some("borrow") => {
+                let output = output_opt.clone().ok_or_else(|| {
+                    SynError::new(span, "`type_out` is required for the `borrow` type")
+                })?;
+                Some(GetTypeConf::Borrow(output))
+            }
+            Some("ok") => Some(GetTypeConf::Ok_(err_opt.clone())),
             _ => Err(SynError::new(span, "unreachable result"))?,
         };
+        let choice = match namevalue_params.get("map") {
+            None => choice,
+            Some(_) if choice.is_some() => {
+                Err(SynError::new(span, "`map` can not be used with `type`"))?
+            }
+            Some(func) => {
+                let func = parse_str::<syn::Path>(func)
+                    .map_err(|_| SynError::new(span, "`map` should be a path to a function"))?;
+                let output = output_opt.clone().ok_or_else(|| {
+                    SynError::new(
+                        span,
+                        "`type_out` is required to set the output type of `map`",
+                    )
+                })?;
+                Some(GetTypeConf::Map(func, output))
+            }
+        };
+        if output_opt.is_some() {
+            match choice {
+                Some(GetTypeConf::Map(_, _))
+                | Some(GetTypeConf::TryInto(_))
+                | Some(GetTypeConf::Borrow(_)) => {}
+                _ => Err(SynError::new(
+                    span,
+                    "`type_out` is only used for `map`, the `try_into` and `borrow` types",
+                ))?,
+            }
+        }
+        if count_opt.is_some() {
+            match choice {
+                Some(GetTypeConf::Head(_)) | Some(GetTypeConf::Tail(_)) => {}
+                _ => Err(SynError::new(
+                    span,
+                    "`n` is only used for the `head` and `tail` types",
+                ))?,
+            }
+        }
+        if err_opt.is_some() {
+            match choice {
+                Some(GetTypeConf::Ok_(_)) => {}
+                _ => Err(SynError::new(span, "`err` is only used for the `ok` type"))?,
+            }
+        }
         Ok(choice)
     }
 }
@@ -173,12 +770,165 @@ impl SetTypeConf {
             None => None,
             Some("ref") => Some(SetTypeConf::Ref),
             Some("own") => Some(SetTypeConf::Own),
+            Some("replace_if_changed") => Some(SetTypeConf::ReplaceIfChanged),
+            Some("clone_ref") => Some(SetTypeConf::CloneRef),
+            Some("extend") => Some(SetTypeConf::Extend),
+            Some("take_replace") => Some(SetTypeConf::TakeReplace),
+            Some("from_slice") => Some(SetTypeConf::FromSlice),
+            Some("ref_field") => Some(SetTypeConf::RefField),
+            Some("opt") => Some(SetTypeConf::Opt),
+            _ => Err(SynError::new(span, "unreachable result"))?,
+        };
+        Ok(choice)
+    }
+}
+
+impl SetBoundConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let choice = match namevalue_params.get("bound").map(AsRef::as_ref) {
+            None => None,
+            Some("into") => Some(SetBoundConf::Into),
+            Some("as_ref") => Some(SetBoundConf::AsRef),
+            Some("exact") => Some(SetBoundConf::Exact),
+            _ => Err(SynError::new(span, "unreachable result"))?,
+        };
+        Ok(choice)
+    }
+}
+
+impl OrderingConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+    ) -> Option<Self> {
+        // The values have been checked by `check_namevalue_params`.
+        match namevalue_params.get("ordering").map(AsRef::as_ref) {
+            Some("relaxed") => Some(OrderingConf::Relaxed),
+            Some("acquire") => Some(OrderingConf::Acquire),
+            Some("release") => Some(OrderingConf::Release),
+            Some("seq_cst") => Some(OrderingConf::SeqCst),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_ts(&self) -> proc_macro2::TokenStream {
+        match self {
+            OrderingConf::Relaxed => quote!(::core::sync::atomic::Ordering::Relaxed),
+            OrderingConf::Acquire => quote!(::core::sync::atomic::Ordering::Acquire),
+            OrderingConf::Release => quote!(::core::sync::atomic::Ordering::Release),
+            OrderingConf::SeqCst => quote!(::core::sync::atomic::Ordering::SeqCst),
+        }
+    }
+}
+
+impl MutTypeConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let choice = match namevalue_params.get("type").map(AsRef::as_ref) {
+            None => None,
+            Some("full") => Some(MutTypeConf::Full),
+            Some("option") => Some(MutTypeConf::Option_),
+            Some("slice") => Some(MutTypeConf::Slice),
+            Some("auto") => Some(MutTypeConf::Auto),
+            _ => Err(SynError::new(span, "unreachable result"))?,
+        };
+        Ok(choice)
+    }
+}
+
+impl UpdateTypeConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let choice = match namevalue_params.get("type").map(AsRef::as_ref) {
+            None => None,
+            Some("none") => Some(UpdateTypeConf::None_),
+            Some("ref") => Some(UpdateTypeConf::Ref),
+            _ => Err(SynError::new(span, "unreachable result"))?,
+        };
+        Ok(choice)
+    }
+}
+
+impl PushTypeConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let choice = match namevalue_params.get("type").map(AsRef::as_ref) {
+            None => None,
+            Some("none") => Some(PushTypeConf::None_),
+            Some("ref") => Some(PushTypeConf::Ref),
             _ => Err(SynError::new(span, "unreachable result"))?,
         };
         Ok(choice)
     }
 }
 
+impl InlineConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+    ) -> Option<Self> {
+        // The values have been checked by `check_namevalue_params`.
+        match namevalue_params.get("inline").map(AsRef::as_ref) {
+            Some("always") => Some(InlineConf::Always),
+            Some("default") => Some(InlineConf::Default),
+            Some("never") => Some(InlineConf::Never),
+            Some("none") => Some(InlineConf::None_),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_ts(&self) -> proc_macro2::TokenStream {
+        match self {
+            InlineConf::Always => quote!(#[inline(always)]),
+            InlineConf::Default => quote!(#[inline]),
+            InlineConf::Never => quote!(#[inline(never)]),
+            InlineConf::None_ => quote!(),
+        }
+    }
+}
+
+impl RenameRuleConf {
+    fn parse_from_input(input: &str, span: proc_macro2::Span) -> ParseResult<Self> {
+        match input {
+            "camelCase" => Ok(RenameRuleConf::Camel),
+            "PascalCase" => Ok(RenameRuleConf::Pascal),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRuleConf::ScreamingSnake),
+            _ => Err(SynError::new(
+                span,
+                "the rule should be `camelCase`, `PascalCase` or `SCREAMING_SNAKE_CASE`",
+            )),
+        }
+    }
+
+    fn apply(&self, name: &str) -> String {
+        match self {
+            RenameRuleConf::Camel | RenameRuleConf::Pascal => {
+                let mut output = String::new();
+                for word in name.split('_').filter(|word| !word.is_empty()) {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        if output.is_empty() && matches!(self, RenameRuleConf::Camel) {
+                            output.extend(first.to_lowercase());
+                        } else {
+                            output.extend(first.to_uppercase());
+                        }
+                        output.push_str(chars.as_str());
+                    }
+                }
+                output
+            }
+            RenameRuleConf::ScreamingSnake => name.to_uppercase(),
+        }
+    }
+}
+
 impl VisibilityConf {
     pub(crate) fn parse_from_input(
         input: Option<&str>,
@@ -203,25 +953,24 @@ impl VisibilityConf {
             VisibilityConf::Private => Some(quote!()),
         }
     }
+
+    /// Enables the method with the default visibility, or keeps the visibility if it's enabled.
+    pub(crate) fn enable(&mut self) {
+        if let VisibilityConf::Disable = self {
+            *self = VisibilityConf::Crate;
+        }
+    }
 }
 
 impl MethodNameConf {
     pub(crate) fn parse_from_input(
         namevalue_params: &::std::collections::HashMap<&str, String>,
+        current: Option<&Self>,
         span: proc_macro2::Span,
     ) -> ParseResult<Option<Self>> {
-        let name_opt = match namevalue_params.get("name") {
-            None => None,
-            Some(input) => Some(input.to_owned()),
-        };
-        let prefix_opt = match namevalue_params.get("prefix") {
-            None => None,
-            Some(input) => Some(input.to_owned()),
-        };
-        let suffix_opt = match namevalue_params.get("suffix") {
-            None => None,
-            Some(input) => Some(input.to_owned()),
-        };
+        let name_opt = namevalue_params.get("name").map(ToOwned::to_owned);
+        let prefix_opt = namevalue_params.get("prefix").map(ToOwned::to_owned);
+        let suffix_opt = namevalue_params.get("suffix").map(ToOwned::to_owned);
         if let Some(name) = name_opt {
             if prefix_opt.is_some() || suffix_opt.is_some() {
                 Err(SynError::new(
@@ -229,18 +978,46 @@ impl MethodNameConf {
                     "do not set prefix or suffix if name was set",
                 ))
             } else {
+                if !is_method_name(&name) {
+                    Err(SynError::new(span, "`name` is not a valid method name"))?;
+                }
                 Ok(Some(MethodNameConf::Name(name)))
             }
         } else {
+            // The field name is always a valid identifier, so check with a placeholder.
+            let placeholder = format!(
+                "{}x{}",
+                prefix_opt.as_deref().unwrap_or(""),
+                suffix_opt.as_deref().unwrap_or("")
+            );
+            if !is_method_name(&placeholder) {
+                Err(SynError::new(
+                    span,
+                    "`prefix` and `suffix` should form a valid method name",
+                ))?;
+            }
+            // Keep the inherited prefix or suffix if only one of them was set.
+            let (current_prefix, current_suffix) = match current {
+                Some(MethodNameConf::Format { prefix, suffix, .. }) => {
+                    (prefix.as_str(), suffix.as_str())
+                }
+                _ => ("", ""),
+            };
             let choice = match (prefix_opt, suffix_opt) {
-                (Some(prefix), Some(suffix)) => Some(MethodNameConf::Format { prefix, suffix }),
+                (Some(prefix), Some(suffix)) => Some(MethodNameConf::Format {
+                    prefix,
+                    suffix,
+                    explicit: true,
+                }),
                 (Some(prefix), None) => Some(MethodNameConf::Format {
                     prefix,
-                    suffix: "".to_owned(),
+                    suffix: current_suffix.to_owned(),
+                    explicit: true,
                 }),
                 (None, Some(suffix)) => Some(MethodNameConf::Format {
-                    prefix: "".to_owned(),
+                    prefix: current_prefix.to_owned(),
                     suffix,
+                    explicit: true,
                 }),
                 (None, None) => None,
             };
@@ -248,14 +1025,38 @@ impl MethodNameConf {
         }
     }
 
-    pub(crate) fn complete(&self, field_name: &syn::Ident) -> syn::Ident {
+    pub(crate) fn complete(
+        &self,
+        field_name: &syn::Ident,
+        rename_all: Option<&RenameRuleConf>,
+    ) -> ParseResult<syn::Ident> {
         let method_name = match self {
             MethodNameConf::Name(ref name) => name.to_owned(),
-            MethodNameConf::Format { prefix, suffix } => {
-                format!("{}{}{}", prefix, field_name.to_string(), suffix)
+            MethodNameConf::Format {
+                prefix,
+                suffix,
+                explicit,
+            } => {
+                let method_name = format!("{}{}{}", prefix, field_name.unraw(), suffix);
+                // The explicit prefix and suffix are kept as they are.
+                match rename_all {
+                    Some(rule) if !explicit => rule.apply(&method_name),
+                    _ => method_name,
+                }
             }
         };
-        syn::Ident::new(&method_name, field_name.span())
+        if !is_method_name(&method_name) {
+            Err(SynError::new(
+                field_name.span(),
+                format!("`{}` is not a valid method name", method_name),
+            ))?;
+        }
+        parse_ident(&method_name, field_name.span()).map_err(|_| {
+            SynError::new(
+                field_name.span(),
+                format!("`{}` is not a valid method name", method_name),
+            )
+        })
     }
 }
 
@@ -264,128 +1065,955 @@ impl ::std::default::Default for FieldConf {
         Self {
             get: GetFieldConf {
                 vis: VisibilityConf::Crate,
+                inline: None,
                 name: MethodNameConf::Format {
                     prefix: "".to_owned(),
                     suffix: "".to_owned(),
+                    explicit: false,
                 },
                 typ: GetTypeConf::NotSet,
+                is_const: false,
+                ordering: OrderingConf::Relaxed,
+                trait_: None,
+                aliases: Vec::new(),
+            },
+            to: ToFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "to_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
             },
             set: SetFieldConf {
                 vis: VisibilityConf::Crate,
+                inline: None,
                 name: MethodNameConf::Format {
                     prefix: "set_".to_owned(),
                     suffix: "".to_owned(),
+                    explicit: false,
                 },
                 typ: SetTypeConf::Ref,
+                bound: SetBoundConf::Into,
+                ordering: OrderingConf::Relaxed,
+                must_use: true,
+                arg: None,
+                convert: None,
+                trait_: None,
+                aliases: Vec::new(),
             },
             mut_: MutFieldConf {
                 vis: VisibilityConf::Crate,
+                inline: None,
                 name: MethodNameConf::Format {
                     prefix: "mut_".to_owned(),
                     suffix: "".to_owned(),
+                    explicit: false,
                 },
+                typ: MutTypeConf::Full,
+                aliases: Vec::new(),
             },
+            take: TakeFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "take_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+                explicit: false,
+            },
+            replace: ReplaceFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "replace_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+            },
+            pred: PredFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: None,
+                explicit: false,
+            },
+            toggle: ToggleFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "toggle_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+                explicit: false,
+            },
+            update: UpdateFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "update_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+                typ: UpdateTypeConf::None_,
+            },
+            push: PushFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "push_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+                typ: PushTypeConf::None_,
+                explicit: false,
+            },
+            at: AtFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "".to_owned(),
+                    suffix: "_at".to_owned(),
+                    explicit: false,
+                },
+                explicit: false,
+            },
+            into: IntoFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "into_".to_owned(),
+                    suffix: "".to_owned(),
+                    explicit: false,
+                },
+            },
+            redact: RedactFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                name: MethodNameConf::Format {
+                    prefix: "".to_owned(),
+                    suffix: "_redacted".to_owned(),
+                    explicit: false,
+                },
+                placeholder: "<redacted>".to_owned(),
+            },
+            forward: ForwardFieldConf {
+                vis: VisibilityConf::Crate,
+                methods: Vec::new(),
+            },
+            matches: Vec::new(),
+            bits: BitsFieldConf {
+                vis: VisibilityConf::Disable,
+                inline: None,
+                suffix: None,
+                explicit: false,
+            },
+            as_kind: None,
+            rename: None,
+            as_ref: false,
+            as_mut: false,
+            skip: false,
+            method_attrs: Vec::new(),
         }
     }
 }
 
-impl FieldConf {
-    fn apply_attrs(&mut self, meta: &syn::Meta) -> ParseResult<()> {
+impl ::std::default::Default for ContainerConf {
+    fn default() -> Self {
+        Self {
+            skip_prefix: None,
+            prefix: None,
+            readonly: false,
+            skip_all: false,
+            export_names: false,
+            field_names: false,
+            skip_pub_fields: false,
+            default: false,
+            require_accessors: false,
+            doc_alias: false,
+            clippy_allow: false,
+            array_copy_threshold: None,
+            copy_types: Vec::new(),
+            rename_all: None,
+            inline: InlineConf::Always,
+            group_by: GroupByConf::Field,
+            dump: ContainerMethodConf::new("dump_fields"),
+            new: ContainerMethodConf::new("new"),
+            swaps: Vec::new(),
+            computed: Vec::new(),
+            deref: None,
+            deref_mut: None,
+        }
+    }
+}
+
+impl ComputedConf {
+    fn parse_from_list(list: &syn::MetaList) -> ParseResult<Self> {
+        let span = list.ident.span();
+        let (word_params, namevalue_params) = parse_list_params(list)?;
+        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+        let namevalues = check_namevalue_params(
+            &namevalue_params,
+            &[NAME_OPTION, EXPR_OPTION, GET_TYPE_OUT_OPTION],
+        )?;
+        let vis =
+            VisibilityConf::parse_from_input(words[0], span)?.unwrap_or(VisibilityConf::Crate);
+        let name = match namevalues.get("name") {
+            Some(name) if is_method_name(name) => {
+                MethodNameConf::Name(name.clone()).complete(&syn::Ident::new("x", span), None)?
+            }
+            Some(_) => Err(SynError::new(span, "`name` is not a valid method name"))?,
+            None => Err(SynError::new(span, "`name` is required for `computed`"))?,
+        };
+        let expr = namevalues
+            .get("expr")
+            .ok_or_else(|| SynError::new(span, "`expr` is required for `computed`"))?;
+        let expr = parse_str::<proc_macro2::TokenStream>(expr)
+            .ok()
+            .filter(|expr| !expr.is_empty())
+            .ok_or_else(|| SynError::new(span, "`expr` should be an expression"))?;
+        let output = parse_str::<syn::Type>(
+            namevalues
+                .get("type_out")
+                .ok_or_else(|| SynError::new(span, "`type_out` is required for `computed`"))?,
+        )
+        .map_err(|_| SynError::new(span, "`type_out` should be a type"))?;
+        Ok(Self {
+            vis,
+            name,
+            expr,
+            output,
+        })
+    }
+}
+
+impl ContainerMethodConf {
+    fn new(name: &str) -> Self {
+        Self {
+            vis: VisibilityConf::Disable,
+            name: syn::Ident::new(name, proc_macro2::Span::call_site()),
+        }
+    }
+
+    fn apply_attrs(&mut self, list: &syn::MetaList) -> ParseResult<()> {
+        let (word_params, namevalue_params) = parse_list_params(list)?;
+        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+        check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+        self.vis = VisibilityConf::parse_from_input(words[0], list.ident.span())?
+            .unwrap_or(VisibilityConf::Crate);
+        if let Some((_, name)) = namevalue_params.iter().next() {
+            self.name = parse_method_name(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl ContainerConf {
+    fn apply_attrs(&mut self, meta: &syn::Meta) -> ParseResult<bool> {
         match meta {
-            syn::Meta::Word(ident) => {
-                Err(SynError::new(
-                    ident.span(),
-                    "this attribute should not be a word",
-                ))?;
+            syn::Meta::Word(ident) if ident == "dump" => {
+                self.dump.vis = VisibilityConf::Crate;
             }
-            syn::Meta::List(list) => {
-                let mut word_params = ::std::collections::HashSet::new();
-                let mut namevalue_params = ::std::collections::HashMap::new();
+            syn::Meta::Word(ident) if ident == "readonly" => {
+                self.readonly = true;
+            }
+            syn::Meta::Word(ident) if ident == "skip_all" => {
+                self.skip_all = true;
+            }
+            syn::Meta::Word(ident) if ident == "export_names" => {
+                self.export_names = true;
+            }
+            syn::Meta::Word(ident) if ident == "field_names" => {
+                self.field_names = true;
+            }
+            syn::Meta::Word(ident) if ident == "skip_pub_fields" => {
+                self.skip_pub_fields = true;
+            }
+            syn::Meta::Word(ident) if ident == "default" => {
+                self.default = true;
+            }
+            syn::Meta::Word(ident) if ident == "require_accessors" => {
+                self.require_accessors = true;
+            }
+            syn::Meta::Word(ident) if ident == "doc_alias" => {
+                self.doc_alias = true;
+            }
+            syn::Meta::Word(ident) if ident == "clippy_allow" => {
+                self.clippy_allow = true;
+            }
+            syn::Meta::List(list) if list.ident == "dump" => {
+                self.dump.apply_attrs(list)?;
+            }
+            syn::Meta::List(list) if list.ident == "new" => {
+                self.new.apply_attrs(list)?;
+            }
+            syn::Meta::List(list) if list.ident == "computed" => {
+                self.computed.push(ComputedConf::parse_from_list(list)?);
+            }
+            syn::Meta::List(list) if list.ident == "swap" => {
+                let mut idents = Vec::new();
                 for nested_meta in list.nested.iter() {
                     match nested_meta {
-                        syn::NestedMeta::Meta(meta) => match meta {
-                            syn::Meta::Word(ident) => {
-                                if !word_params.insert(ident) {
-                                    Err(SynError::new(
-                                        ident.span(),
-                                        "this attribute has been set twice",
-                                    ))?;
-                                }
-                            }
-                            syn::Meta::NameValue(mnv) => {
-                                let syn::MetaNameValue { ident, lit, .. } = mnv;
-                                if let syn::Lit::Str(content) = lit {
-                                    if namevalue_params.insert(ident, content).is_some() {
-                                        Err(SynError::new(
-                                            ident.span(),
-                                            "this attribute has been set twice",
-                                        ))?;
-                                    }
-                                } else {
-                                    Err(SynError::new(
-                                        lit.span(),
-                                        "this literal should be a string literal",
-                                    ))?;
-                                }
-                            }
-                            _ => {
-                                Err(SynError::new(
-                                    meta.span(),
-                                    "this attribute should be a word",
-                                ))?;
-                            }
-                        },
-                        syn::NestedMeta::Literal(lit) => {
-                            Err(SynError::new(
-                                lit.span(),
-                                "this attribute should not be a literal",
-                            ))?;
-                        }
+                        syn::NestedMeta::Meta(syn::Meta::Word(ident)) => idents.push(ident.clone()),
+                        _ => Err(SynError::new(
+                            nested_meta.span(),
+                            "this should be a field name",
+                        ))?,
                     }
                 }
-                if word_params.is_empty() && namevalue_params.is_empty() {
+                // The visibility is optional, e.g. `swap(public, a, b)`.
+                let vis = if idents.len() == 3 {
+                    let word = idents.remove(0);
+                    match word.to_string().as_ref() {
+                        "disable" => VisibilityConf::Disable,
+                        "public" => VisibilityConf::Public,
+                        "crate" => VisibilityConf::Crate,
+                        "private" => VisibilityConf::Private,
+                        _ => Err(SynError::new(
+                            word.span(),
+                            "expected one of: disable, public, crate, private",
+                        ))?,
+                    }
+                } else {
+                    VisibilityConf::Crate
+                };
+                if idents.len() != 2 || idents[0] == idents[1] {
                     Err(SynError::new(
                         list.span(),
-                        "this attribute should not be empty",
+                        "`swap` requires two different fields",
+                    ))?;
+                }
+                let second = idents.pop().expect("there should be two fields");
+                let first = idents.pop().expect("there should be two fields");
+                self.swaps.push((vis, first, second));
+            }
+            // It has been applied to the default field configuration.
+            syn::Meta::List(list) if list.ident == "accessor_prefix" => {}
+            syn::Meta::NameValue(mnv) if mnv.ident == "preset" || mnv.ident == "visibility" => {}
+            syn::Meta::NameValue(mnv) if mnv.ident == "array_copy_threshold" => {
+                if self.array_copy_threshold.is_some() {
+                    Err(SynError::new(
+                        mnv.span(),
+                        "this attribute has been set twice",
                     ))?;
                 }
+                let threshold_opt = match mnv.lit {
+                    syn::Lit::Int(ref lit) => Some(lit.value() as usize),
+                    syn::Lit::Str(ref lit) => lit.value().parse::<usize>().ok(),
+                    _ => None,
+                };
+                if threshold_opt.is_none() {
+                    Err(SynError::new(
+                        mnv.lit.span(),
+                        "`array_copy_threshold` should be an unsigned integer",
+                    ))?;
+                }
+                self.array_copy_threshold = threshold_opt;
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "copy_types" => {
+                let paths = match mnv.lit {
+                    syn::Lit::Str(ref lit) => parse_str_with(
+                        syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                        &lit.value(),
+                    )
+                    .map_err(|_| {
+                        SynError::new(lit.span(), "`copy_types` should be a list of paths")
+                    })?,
+                    _ => Err(SynError::new(
+                        mnv.lit.span(),
+                        "this literal should be a string literal",
+                    ))?,
+                };
+                self.copy_types.extend(paths);
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "deref" || mnv.ident == "deref_mut" => {
+                let target = if mnv.ident == "deref" {
+                    &mut self.deref
+                } else {
+                    &mut self.deref_mut
+                };
+                let mut name = None;
+                set_string_once(&mut name, mnv)?;
+                let name = name.expect("the name has been set");
+                if target.is_some() {
+                    Err(SynError::new(
+                        mnv.ident.span(),
+                        "this attribute has been set twice",
+                    ))?;
+                }
+                if !is_method_name(&name) {
+                    Err(SynError::new(
+                        mnv.lit.span(),
+                        "this is not a valid field name",
+                    ))?;
+                }
+                let name = parse_ident(&name, mnv.lit.span())
+                    .map_err(|_| SynError::new(mnv.lit.span(), "this is not a valid field name"))?;
+                *target = Some(name);
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "skip_prefix" => {
+                set_string_once(&mut self.skip_prefix, mnv)?;
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "prefix" => {
+                set_string_once(&mut self.prefix, mnv)?;
+                if let syn::Lit::Str(ref content) = mnv.lit {
+                    check_affix(&mnv.ident, content)?;
+                }
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "inline" => {
+                let mut inline = None;
+                match inline_from_bool(&mnv.lit) {
+                    Some(content) => inline = Some(content.value()),
+                    None => set_string_once(&mut inline, mnv)?,
+                }
+                self.inline = match inline.as_ref().map(AsRef::as_ref) {
+                    Some("always") => InlineConf::Always,
+                    Some("default") => InlineConf::Default,
+                    Some("never") => InlineConf::Never,
+                    Some("none") => InlineConf::None_,
+                    _ => Err(SynError::new(
+                        mnv.lit.span(),
+                        "the inline hint should be `always`, `default`, `never` or `none`",
+                    ))?,
+                };
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "group_by" => {
+                let mut group_by = None;
+                set_string_once(&mut group_by, mnv)?;
+                self.group_by = match group_by.as_ref().map(AsRef::as_ref) {
+                    Some("field") => GroupByConf::Field,
+                    Some("kind") => GroupByConf::Kind,
+                    _ => Err(SynError::new(
+                        mnv.lit.span(),
+                        "the methods should be grouped by `field` or `kind`",
+                    ))?,
+                };
+            }
+            syn::Meta::NameValue(mnv) if mnv.ident == "rename_all" => {
+                if self.rename_all.is_some() {
+                    Err(SynError::new(
+                        mnv.ident.span(),
+                        "this attribute has been set twice",
+                    ))?;
+                }
+                if let syn::Lit::Str(ref content) = mnv.lit {
+                    self.rename_all = Some(RenameRuleConf::parse_from_input(
+                        &content.value(),
+                        content.span(),
+                    )?);
+                } else {
+                    Err(SynError::new(
+                        mnv.lit.span(),
+                        "this literal should be a string literal",
+                    ))?;
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+fn parse_trait_from_input(
+    namevalue_params: &::std::collections::HashMap<&str, String>,
+    span: proc_macro2::Span,
+) -> ParseResult<Option<syn::Path>> {
+    namevalue_params
+        .get("trait")
+        .map(|path| {
+            parse_str::<syn::Path>(path)
+                .map_err(|_| SynError::new(span, "`trait` should be a path to a trait"))
+        })
+        .transpose()
+}
+
+fn parse_attrs_from_input(
+    namevalue_params: &::std::collections::HashMap<&str, String>,
+    span: proc_macro2::Span,
+) -> ParseResult<Vec<syn::Attribute>> {
+    let parse_attr = |content: String, msg: &str| {
+        parse_str_with(syn::Attribute::parse_outer, &format!("#[{}]", content))
+            .ok()
+            .and_then(|mut attrs| attrs.pop().filter(|_| attrs.is_empty()))
+            .ok_or_else(|| SynError::new(span, msg))
+    };
+    let mut attrs = Vec::new();
+    if let Some(attr) = namevalue_params.get("attr") {
+        attrs.push(parse_attr(
+            attr.to_owned(),
+            "`attr` should be the content of an attribute",
+        )?);
+    }
+    if let Some(cfg) = namevalue_params.get("cfg") {
+        let msg = "`cfg` should be a configuration predicate";
+        let attr = parse_attr(format!("cfg({})", cfg), msg)?;
+        // The predicate is a meta item, such as `debug_assertions` or `feature = "xxx"`.
+        attr.parse_meta().map_err(|_| SynError::new(span, msg))?;
+        attrs.push(attr);
+    }
+    Ok(attrs)
+}
+
+fn parse_alias_from_input(
+    namevalue_params: &::std::collections::HashMap<&str, String>,
+    span: proc_macro2::Span,
+) -> ParseResult<Option<syn::Ident>> {
+    match namevalue_params.get("alias") {
+        None => Ok(None),
+        Some(alias) if is_method_name(alias) => Ok(Some(
+            MethodNameConf::Name(alias.to_owned()).complete(&syn::Ident::new("x", span), None)?,
+        )),
+        Some(_) => Err(SynError::new(span, "`alias` is not a valid method name")),
+    }
+}
+
+/// Checks whether the name is an identifier, or a keyword which could be a raw identifier.
+///
+/// Check it by hand with the identifier rules of Rust, i.e. `XID_Start` and `XID_Continue`, since
+/// the lexer reports invalid tokens as errors rather than results.
+fn is_method_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c == '_' || c.is_xid_start() => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_xid_continue()) && !["_", "self", "Self", "super", "crate"].contains(&name)
+}
+
+/// Checks whether the tokens could be the pattern of a match arm.
+///
+/// Patterns can not be parsed without the `full` feature of syn, so only reject the tokens which
+/// would end the pattern early, i.e. a top-level `=>`, an `if` guard or a `,`.
+fn is_pattern(tokens: &proc_macro2::TokenStream) -> bool {
+    let mut last_eq = false;
+    !tokens.is_empty()
+        && tokens.clone().into_iter().all(|tt| {
+            let is_valid = match tt {
+                proc_macro2::TokenTree::Ident(ref ident) => ident != "if",
+                proc_macro2::TokenTree::Punct(ref punct) => match punct.as_char() {
+                    ',' => false,
+                    '>' => !last_eq,
+                    _ => true,
+                },
+                _ => true,
+            };
+            last_eq = match tt {
+                proc_macro2::TokenTree::Punct(ref punct) => {
+                    punct.as_char() == '=' && punct.spacing() == proc_macro2::Spacing::Joint
+                }
+                _ => false,
+            };
+            is_valid
+        })
+}
+
+/// Parses the name as an identifier with the span.
+fn parse_ident(name: &str, span: proc_macro2::Span) -> ParseResult<syn::Ident> {
+    // Keywords, such as `type`, are only allowed as raw identifiers.
+    // The keywords added since the 2018 edition are unknown to syn, so check them here.
+    let mut ident = parse_str::<syn::Ident>(name)
+        .and_then(|ident| {
+            if NEW_KEYWORDS.contains(&name) {
+                Err(SynError::new(ident.span(), "keyword"))
+            } else {
+                Ok(ident)
+            }
+        })
+        .or_else(|_| parse_str::<syn::Ident>(&format!("r#{}", name)))?;
+    ident.set_span(span);
+    Ok(ident)
+}
+
+/// Checks whether the prefix or the suffix could be a part of a method name.
+fn check_affix(ident: &syn::Ident, content: &syn::LitStr) -> ParseResult<()> {
+    let value = content.value();
+    let name = if ident == "prefix" {
+        format!("{}x", value)
+    } else {
+        format!("x{}", value)
+    };
+    if !is_method_name(&name) {
+        Err(SynError::new(
+            content.span(),
+            format!("this is not a valid {}", ident),
+        ))?;
+    }
+    Ok(())
+}
+
+fn parse_method_name(lit: &syn::LitStr) -> ParseResult<syn::Ident> {
+    let name = lit.value();
+    if !is_method_name(&name) {
+        Err(SynError::new(lit.span(), "this is not a valid method name"))?;
+    }
+    MethodNameConf::Name(name).complete(&syn::Ident::new("x", lit.span()), None)
+}
+
+/// Converts `inline = true` and `inline = false` into `inline = "always"` and `inline = "none"`.
+fn inline_from_bool(lit: &syn::Lit) -> Option<syn::LitStr> {
+    match lit {
+        syn::Lit::Bool(value) => {
+            let choice = if value.value { "always" } else { "none" };
+            Some(syn::LitStr::new(choice, value.span))
+        }
+        _ => None,
+    }
+}
+
+fn set_string_once(target: &mut Option<String>, mnv: &syn::MetaNameValue) -> ParseResult<()> {
+    if let syn::Lit::Str(ref content) = mnv.lit {
+        if target.is_some() {
+            Err(SynError::new(
+                mnv.ident.span(),
+                "this attribute has been set twice",
+            ))?;
+        }
+        *target = Some(content.value());
+    } else {
+        Err(SynError::new(
+            mnv.lit.span(),
+            "this literal should be a string literal",
+        ))?;
+    }
+    Ok(())
+}
+
+impl FieldConf {
+    /// Sets the visibility of the methods which are enabled by default.
+    fn apply_visibility(&mut self, mnv: &syn::MetaNameValue) -> ParseResult<()> {
+        let choice = match mnv.lit {
+            syn::Lit::Str(ref lit) => match lit.value().as_ref() {
+                "public" => VisibilityConf::Public,
+                "crate" => VisibilityConf::Crate,
+                "private" => VisibilityConf::Private,
+                _ => Err(SynError::new(
+                    lit.span(),
+                    "expected one of: public, crate, private",
+                ))?,
+            },
+            _ => Err(SynError::new(
+                mnv.lit.span(),
+                "this literal should be a string literal",
+            ))?,
+        };
+        for vis in [
+            &mut self.get.vis,
+            &mut self.to.vis,
+            &mut self.set.vis,
+            &mut self.mut_.vis,
+            &mut self.take.vis,
+            &mut self.replace.vis,
+            &mut self.pred.vis,
+            &mut self.toggle.vis,
+            &mut self.update.vis,
+            &mut self.push.vis,
+            &mut self.at.vis,
+            &mut self.into.vis,
+            &mut self.redact.vis,
+            &mut self.bits.vis,
+            &mut self.forward.vis,
+        ] {
+            if let VisibilityConf::Disable = *vis {
+                continue;
+            }
+            *vis = choice.clone();
+        }
+        Ok(())
+    }
+
+    fn apply_preset(&mut self, mnv: &syn::MetaNameValue) -> ParseResult<()> {
+        let preset = match mnv.lit {
+            syn::Lit::Str(ref lit) => lit.value(),
+            _ => Err(SynError::new(
+                mnv.lit.span(),
+                "this literal should be a string literal",
+            ))?,
+        };
+        let attr: syn::Attribute = match preset.as_ref() {
+            "readonly" => syn::parse_quote!(#[property(skip(set, mut))]),
+            "builder" => syn::parse_quote!(#[property(get(public), set(public, type = "own"))]),
+            "plain" => syn::parse_quote!(#[property(get(public), set(public), mut(public))]),
+            _ => Err(SynError::new(
+                mnv.lit.span(),
+                "expected one of: readonly, builder, plain",
+            ))?,
+        };
+        for meta in parse_attrs(mnv.span(), &[attr])?.iter() {
+            self.apply_attrs(meta)?;
+        }
+        Ok(())
+    }
+
+    fn apply_accessor_prefix(&mut self, list: &syn::MetaList) -> ParseResult<()> {
+        let (word_params, namevalue_params) = parse_list_params(list)?;
+        check_word_params(&word_params, &[])?;
+        let namevalues = check_namevalue_params(
+            &namevalue_params,
+            &[
+                ("get", None),
+                ("to", None),
+                ("set", None),
+                ("mut", None),
+                ("mut_", None),
+                ("take", None),
+                ("replace", None),
+                ("pred", None),
+                ("toggle", None),
+                ("update", None),
+                ("push", None),
+                ("at", None),
+                ("into", None),
+                ("redact", None),
+            ],
+        )?;
+        let span = list.ident.span();
+        for (accessor, prefix) in namevalues.into_iter() {
+            let mut params = ::std::collections::HashMap::new();
+            let _ = params.insert("prefix", prefix);
+            let name = match accessor {
+                "get" => &mut self.get.name,
+                "to" => &mut self.to.name,
+                "set" => &mut self.set.name,
+                "mut" | "mut_" => &mut self.mut_.name,
+                "take" => &mut self.take.name,
+                "replace" => &mut self.replace.name,
+                "toggle" => &mut self.toggle.name,
+                "update" => &mut self.update.name,
+                "push" => &mut self.push.name,
+                "at" => &mut self.at.name,
+                "into" => &mut self.into.name,
+                "redact" => &mut self.redact.name,
+                "pred" => {
+                    self.pred.name =
+                        MethodNameConf::parse_from_input(&params, self.pred.name.as_ref(), span)?;
+                    continue;
+                }
+                _ => Err(SynError::new(span, "unreachable result"))?,
+            };
+            if let Some(choice) = MethodNameConf::parse_from_input(&params, Some(name), span)? {
+                *name = choice;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_attrs(&mut self, meta: &syn::Meta) -> ParseResult<()> {
+        match meta {
+            syn::Meta::Word(ident) => {
+                if ident == "skip" {
+                    self.skip = true;
+                } else if ident == "as_ref" {
+                    self.as_ref = true;
+                } else if ident == "as_mut" {
+                    self.as_mut = true;
+                } else if ident == "bits" {
+                    self.bits.vis.enable();
+                    self.bits.explicit = true;
+                } else if ident == "push" {
+                    self.push.vis.enable();
+                    self.push.explicit = true;
+                } else if ident == "at" {
+                    self.at.vis.enable();
+                    self.at.explicit = true;
+                } else if ident == "into" {
+                    self.into.vis.enable();
+                } else if ident == "redact" {
+                    self.redact.vis.enable();
+                } else if ident == "get" {
+                    self.get.vis.enable();
+                } else if ident == "to" {
+                    self.to.vis.enable();
+                } else if ident == "set" {
+                    self.set.vis.enable();
+                } else if ident == "mut" || ident == "mut_" {
+                    self.mut_.vis.enable();
+                } else if ident == "take" {
+                    self.take.vis.enable();
+                    self.take.explicit = true;
+                } else if ident == "replace" {
+                    self.replace.vis.enable();
+                } else if ident == "pred" {
+                    self.pred.vis.enable();
+                    self.pred.explicit = true;
+                } else if ident == "toggle" {
+                    self.toggle.vis.enable();
+                    self.toggle.explicit = true;
+                } else if ident == "update" {
+                    self.update.vis.enable();
+                } else {
+                    Err(SynError::new(
+                        ident.span(),
+                        "this attribute should not be a word",
+                    ))?;
+                }
+            }
+            syn::Meta::List(list) => {
+                let (word_params, namevalue_params) = parse_list_params(list)?;
                 match list.ident.to_string().as_ref() {
+                    "skip" => {
+                        check_namevalue_params(&namevalue_params, &[])?;
+                        for p in word_params.iter() {
+                            match p.to_string().as_ref() {
+                                "get" => self.get.vis = VisibilityConf::Disable,
+                                "to" => self.to.vis = VisibilityConf::Disable,
+                                "set" => self.set.vis = VisibilityConf::Disable,
+                                "mut" | "mut_" => self.mut_.vis = VisibilityConf::Disable,
+                                "take" => self.take.vis = VisibilityConf::Disable,
+                                "replace" => self.replace.vis = VisibilityConf::Disable,
+                                "pred" => self.pred.vis = VisibilityConf::Disable,
+                                "toggle" => self.toggle.vis = VisibilityConf::Disable,
+                                "update" => self.update.vis = VisibilityConf::Disable,
+                                "push" => self.push.vis = VisibilityConf::Disable,
+                                "at" => self.at.vis = VisibilityConf::Disable,
+                                "into" => self.into.vis = VisibilityConf::Disable,
+                                "redact" => self.redact.vis = VisibilityConf::Disable,
+                                "bits" => self.bits.vis = VisibilityConf::Disable,
+                                _ => Err(SynError::new(p.span(), "this accessor was unknown"))?,
+                            }
+                        }
+                    }
                     "get" => {
-                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let words = check_word_params(
+                            &word_params,
+                            &[VISIBILITY_OPTIONS, CONST_OPTIONS, GET_COPY_OPTIONS],
+                        )?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, GET_TYPE_OPTIONS],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                GET_TYPE_OPTIONS,
+                                GET_COUNT_OPTION,
+                                GET_MAP_OPTION,
+                                GET_TYPE_OUT_OPTION,
+                                GET_ERR_OPTION,
+                                GET_ORDERING_OPTIONS,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                                TRAIT_OPTION,
+                                ALIAS_OPTION,
+                            ],
                         )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.get.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Get, attr));
+                        }
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(words[0], list.ident.span())?
                         {
                             self.get.vis = choice;
                         }
-                        if let Some(choice) =
-                            MethodNameConf::parse_from_input(&namevalues, list.ident.span())?
-                        {
+                        if words[1].is_some() {
+                            self.get.is_const = true;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.get.name),
+                            list.ident.span(),
+                        )? {
                             self.get.name = choice;
                         }
                         if let Some(choice) =
                             GetTypeConf::parse_from_input(&namevalues, list.ident.span())?
                         {
+                            if words[2].is_some() {
+                                Err(SynError::new(
+                                    list.ident.span(),
+                                    "`copy` can not be used with `type`",
+                                ))?;
+                            }
                             self.get.typ = choice;
+                        } else if words[2].is_some() {
+                            self.get.typ = GetTypeConf::Copy_;
+                        }
+                        if let Some(choice) = OrderingConf::parse_from_input(&namevalues) {
+                            self.get.ordering = choice;
+                        }
+                        if let Some(choice) =
+                            parse_trait_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.get.trait_ = Some(choice);
+                        }
+                        if let Some(alias) = parse_alias_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.get.aliases.push(alias);
                         }
                     }
-                    "set" => {
+                    "to" => {
                         let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, SET_TYPE_OPTIONS],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
                         )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.to.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::To, attr));
+                        }
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(words[0], list.ident.span())?
                         {
-                            self.set.vis = choice;
+                            self.to.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.to.name),
+                            list.ident.span(),
+                        )? {
+                            self.to.name = choice;
+                        }
+                    }
+                    "set" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                SET_TYPE_OPTIONS,
+                                SET_BOUND_OPTIONS,
+                                SET_ORDERING_OPTIONS,
+                                SET_MUST_USE_OPTIONS,
+                                SET_ARG_OPTION,
+                                SET_CONVERT_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                                TRAIT_OPTION,
+                                ALIAS_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.set.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Set, attr));
                         }
                         if let Some(choice) =
-                            MethodNameConf::parse_from_input(&namevalues, list.ident.span())?
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
                         {
+                            self.set.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.set.name),
+                            list.ident.span(),
+                        )? {
                             self.set.name = choice;
                         }
                         if let Some(choice) =
@@ -393,38 +2021,598 @@ impl FieldConf {
                         {
                             self.set.typ = choice;
                         }
+                        if let Some(choice) =
+                            SetBoundConf::parse_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.set.bound = choice;
+                        }
+                        if let Some(choice) = OrderingConf::parse_from_input(&namevalues) {
+                            self.set.ordering = choice;
+                        }
+                        if let Some(must_use) = namevalues.get("must_use") {
+                            self.set.must_use = must_use == "true";
+                        }
+                        if let Some(arg) = namevalues.get("arg") {
+                            let arg = parse_str::<syn::Type>(arg).map_err(|_| {
+                                SynError::new(list.ident.span(), "`arg` should be a type")
+                            })?;
+                            self.set.arg = Some(arg);
+                        }
+                        if let Some(convert) = namevalues.get("convert") {
+                            let convert = parse_str::<proc_macro2::TokenStream>(convert)
+                                .ok()
+                                .filter(|convert| !convert.is_empty())
+                                .ok_or_else(|| {
+                                    SynError::new(
+                                        list.ident.span(),
+                                        "`convert` should be an expression",
+                                    )
+                                })?;
+                            self.set.convert = Some(convert);
+                        }
+                        if let Some(choice) =
+                            parse_trait_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.set.trait_ = Some(choice);
+                        }
+                        if let Some(alias) = parse_alias_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.set.aliases.push(alias);
+                        }
                     }
                     "mut" => {
                         let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                MUT_TYPE_OPTIONS,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                                ALIAS_OPTION,
+                            ],
                         )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.mut_.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Mut, attr));
+                        }
+                        if let Some(alias) = parse_alias_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.mut_.aliases.push(alias);
+                        }
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(words[0], list.ident.span())?
                         {
                             self.mut_.vis = choice;
                         }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.mut_.name),
+                            list.ident.span(),
+                        )? {
+                            self.mut_.name = choice;
+                        }
                         if let Some(choice) =
-                            MethodNameConf::parse_from_input(&namevalues, list.ident.span())?
+                            MutTypeConf::parse_from_input(&namevalues, list.ident.span())?
                         {
-                            self.mut_.name = choice;
+                            self.mut_.typ = choice;
+                        }
+                    }
+                    "take" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.take.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Take, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.take.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.take.name),
+                            list.ident.span(),
+                        )? {
+                            self.take.name = choice;
+                        }
+                        self.take.explicit = true;
+                    }
+                    "replace" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.replace.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Replace, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.replace.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.replace.name),
+                            list.ident.span(),
+                        )? {
+                            self.replace.name = choice;
+                        }
+                    }
+                    "pred" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.pred.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Pred, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.pred.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            self.pred.name.as_ref(),
+                            list.ident.span(),
+                        )? {
+                            self.pred.name = Some(choice);
+                        }
+                        self.pred.explicit = true;
+                    }
+                    "toggle" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.toggle.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Toggle, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.toggle.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.toggle.name),
+                            list.ident.span(),
+                        )? {
+                            self.toggle.name = choice;
+                        }
+                        self.toggle.explicit = true;
+                    }
+                    "at" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.at.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::At, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.at.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.at.name),
+                            list.ident.span(),
+                        )? {
+                            self.at.name = choice;
+                        }
+                        self.at.explicit = true;
+                    }
+                    "into" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.into.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Into, attr));
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.into.vis = choice;
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.into.name),
+                            list.ident.span(),
+                        )? {
+                            self.into.name = choice;
+                        }
+                    }
+                    "redact" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                PLACEHOLDER_OPTION,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.redact.inline = Some(choice);
+                        }
+                        if let Some(placeholder) = namevalues.get("placeholder") {
+                            self.redact.placeholder = placeholder.to_owned();
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Redact, attr));
+                        }
+                        match VisibilityConf::parse_from_input(words[0], list.ident.span())? {
+                            Some(choice) => self.redact.vis = choice,
+                            None => self.redact.vis.enable(),
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.redact.name),
+                            list.ident.span(),
+                        )? {
+                            self.redact.name = choice;
                         }
                     }
+                    "update" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                UPDATE_TYPE_OPTIONS,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.update.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Update, attr));
+                        }
+                        match VisibilityConf::parse_from_input(words[0], list.ident.span())? {
+                            Some(choice) => self.update.vis = choice,
+                            None => self.update.vis.enable(),
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.update.name),
+                            list.ident.span(),
+                        )? {
+                            self.update.name = choice;
+                        }
+                        if let Some(choice) =
+                            UpdateTypeConf::parse_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.update.typ = choice;
+                        }
+                    }
+                    "push" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                PUSH_TYPE_OPTIONS,
+                                INLINE_OPTIONS,
+                                ATTR_OPTION,
+                                CFG_OPTION,
+                            ],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.push.inline = Some(choice);
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Push, attr));
+                        }
+                        match VisibilityConf::parse_from_input(words[0], list.ident.span())? {
+                            Some(choice) => self.push.vis = choice,
+                            None => self.push.vis.enable(),
+                        }
+                        if let Some(choice) = MethodNameConf::parse_from_input(
+                            &namevalues,
+                            Some(&self.push.name),
+                            list.ident.span(),
+                        )? {
+                            self.push.name = choice;
+                        }
+                        if let Some(choice) =
+                            PushTypeConf::parse_from_input(&namevalues, list.ident.span())?
+                        {
+                            self.push.typ = choice;
+                        }
+                        self.push.explicit = true;
+                    }
+                    "forward" => {
+                        check_namevalue_params(&namevalue_params, &[])?;
+                        for p in word_params.iter() {
+                            if !VISIBILITY_OPTIONS.iter().any(|opt| p == opt)
+                                && !FORWARD_METHODS.iter().any(|method| p == method)
+                            {
+                                Err(SynError::new(
+                                    p.span(),
+                                    format!(
+                                        "this method can not be forwarded, expected one of: {}",
+                                        FORWARD_METHODS.join(", ")
+                                    ),
+                                ))?;
+                            }
+                        }
+                        let words = check_word_params(
+                            &word_params,
+                            &[VISIBILITY_OPTIONS, FORWARD_METHODS],
+                        )?;
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                        {
+                            self.forward.vis = choice;
+                        }
+                        // Keep the order of the methods.
+                        for nested_meta in list.nested.iter() {
+                            if let syn::NestedMeta::Meta(syn::Meta::Word(ident)) = nested_meta {
+                                if FORWARD_METHODS.iter().any(|method| ident == method) {
+                                    self.forward.methods.push(ident.clone());
+                                }
+                            }
+                        }
+                    }
+                    "matches" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PATTERN_OPTION],
+                        )?;
+                        let vis = VisibilityConf::parse_from_input(words[0], list.ident.span())?
+                            .unwrap_or(VisibilityConf::Crate);
+                        let name = match namevalues.get("name") {
+                            Some(name) if is_method_name(name) => {
+                                MethodNameConf::Name(name.clone())
+                                    .complete(&syn::Ident::new("x", list.ident.span()), None)?
+                            }
+                            Some(_) => Err(SynError::new(
+                                list.ident.span(),
+                                "`name` is not a valid method name",
+                            ))?,
+                            None => Err(SynError::new(
+                                list.ident.span(),
+                                "`name` is required for `matches`",
+                            ))?,
+                        };
+                        let pattern = namevalues.get("pattern").ok_or_else(|| {
+                            SynError::new(list.ident.span(), "`pattern` is required for `matches`")
+                        })?;
+                        let pattern = parse_str::<proc_macro2::TokenStream>(pattern)
+                            .ok()
+                            .filter(is_pattern)
+                            .ok_or_else(|| {
+                                SynError::new(list.ident.span(), "`pattern` should be a pattern")
+                            })?;
+                        self.matches.push(MatchesFieldConf { vis, name, pattern });
+                    }
+                    "bits" => {
+                        let words = check_word_params(&word_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[SUFFIX_OPTION, INLINE_OPTIONS, ATTR_OPTION, CFG_OPTION],
+                        )?;
+                        if let Some(choice) = InlineConf::parse_from_input(&namevalues) {
+                            self.bits.inline = Some(choice);
+                        }
+                        if let Some(suffix) = namevalues.get("suffix") {
+                            self.bits.suffix = Some(suffix.to_owned());
+                        }
+                        for attr in parse_attrs_from_input(&namevalues, list.ident.span())? {
+                            self.method_attrs.push((MethodKind::Bits, attr));
+                        }
+                        match VisibilityConf::parse_from_input(words[0], list.ident.span())? {
+                            Some(choice) => self.bits.vis = choice,
+                            None => self.bits.vis.enable(),
+                        }
+                        self.bits.explicit = true;
+                    }
                     _ => {
                         Err(SynError::new(list.ident.span(), "unsupport attribute"))?;
                     }
                 }
             }
             syn::Meta::NameValue(name_value) => {
+                let syn::MetaNameValue { ident, lit, .. } = name_value;
+                match (ident.to_string().as_ref(), lit) {
+                    ("as", syn::Lit::Str(content)) => {
+                        let choice = match content.value().as_ref() {
+                            "number" => FieldTypeHint::Number,
+                            "copy" => FieldTypeHint::Copy_,
+                            "clone" => FieldTypeHint::Clone_,
+                            _ => Err(SynError::new(content.span(), "this attribute was unknown"))?,
+                        };
+                        self.as_kind = Some(choice);
+                    }
+                    ("redact", syn::Lit::Str(content)) => {
+                        self.redact.placeholder = content.value();
+                        self.redact.vis.enable();
+                    }
+                    ("rename", syn::Lit::Str(content)) => {
+                        if self.rename.is_some() {
+                            Err(SynError::new(
+                                ident.span(),
+                                "this attribute has been set twice",
+                            ))?;
+                        }
+                        let name = content.value();
+                        if !is_method_name(&name) {
+                            Err(SynError::new(content.span(), "this is not a valid name"))?;
+                        }
+                        let name = parse_ident(&name, content.span()).map_err(|_| {
+                            SynError::new(content.span(), "this is not a valid name")
+                        })?;
+                        self.rename = Some(name);
+                    }
+                    ("as", _) | ("redact", _) | ("rename", _) => {
+                        Err(SynError::new(
+                            lit.span(),
+                            "this literal should be a string literal",
+                        ))?;
+                    }
+                    _ => {
+                        Err(SynError::new(
+                            name_value.span(),
+                            "this attribute should not be a name-value pair",
+                        ))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_list_params(
+    list: &syn::MetaList,
+) -> ParseResult<(
+    ::std::collections::HashSet<&syn::Ident>,
+    ::std::collections::HashMap<&syn::Ident, syn::LitStr>,
+)> {
+    let mut word_params = ::std::collections::HashSet::new();
+    let mut namevalue_params = ::std::collections::HashMap::new();
+    for nested_meta in list.nested.iter() {
+        match nested_meta {
+            syn::NestedMeta::Meta(meta) => match meta {
+                syn::Meta::Word(ident) => {
+                    if !word_params.insert(ident) {
+                        Err(SynError::new(
+                            ident.span(),
+                            "this attribute has been set twice",
+                        ))?;
+                    }
+                }
+                syn::Meta::NameValue(mnv) => {
+                    let syn::MetaNameValue { ident, lit, .. } = mnv;
+                    let content = match (lit, inline_from_bool(lit)) {
+                        (syn::Lit::Str(content), _) => content.clone(),
+                        (_, Some(content)) if ident == "inline" => content,
+                        _ => Err(SynError::new(
+                            lit.span(),
+                            "this literal should be a string literal",
+                        ))?,
+                    };
+                    if ident == "prefix" || ident == "suffix" {
+                        check_affix(ident, &content)?;
+                    }
+                    if namevalue_params.insert(ident, content).is_some() {
+                        Err(SynError::new(
+                            ident.span(),
+                            "this attribute has been set twice",
+                        ))?;
+                    }
+                }
+                _ => {
+                    Err(SynError::new(
+                        meta.span(),
+                        "this attribute should be a word",
+                    ))?;
+                }
+            },
+            syn::NestedMeta::Literal(lit) => {
                 Err(SynError::new(
-                    name_value.span(),
-                    "this attribute should not be a name-value pair",
+                    lit.span(),
+                    "this attribute should not be a literal",
                 ))?;
             }
         }
-        Ok(())
     }
+    if word_params.is_empty() && namevalue_params.is_empty() {
+        Err(SynError::new(
+            list.span(),
+            "this attribute should not be empty",
+        ))?;
+    }
+    Ok((word_params, namevalue_params))
 }
 
 fn check_word_params<'a>(
@@ -455,7 +2643,7 @@ fn check_word_params<'a>(
 }
 
 fn check_namevalue_params<'a>(
-    params: &::std::collections::HashMap<&syn::Ident, &syn::LitStr>,
+    params: &::std::collections::HashMap<&syn::Ident, syn::LitStr>,
     options: &[(&'a str, Option<&[&'a str]>)],
 ) -> ParseResult<::std::collections::HashMap<&'a str, String>> {
     let mut result = ::std::collections::HashMap::new();
@@ -476,6 +2664,10 @@ fn check_namevalue_params<'a>(
                     if find {
                         break;
                     }
+                    Err(SynError::new(
+                        v.span(),
+                        format!("expected one of: {}", group.join(", ")),
+                    ))?;
                 } else {
                     let _ = result.insert(*k, value);
                     find = true;
@@ -490,11 +2682,8 @@ fn check_namevalue_params<'a>(
     Ok(result)
 }
 
-fn parse_attrs(
-    span: proc_macro2::Span,
-    mut conf: FieldConf,
-    attrs: &[syn::Attribute],
-) -> ParseResult<FieldConf> {
+fn parse_attrs(span: proc_macro2::Span, attrs: &[syn::Attribute]) -> ParseResult<Vec<syn::Meta>> {
+    let mut metas = Vec::new();
     for attr in attrs.iter() {
         if let syn::AttrStyle::Outer = attr.style {
             let meta = attr
@@ -520,7 +2709,7 @@ fn parse_attrs(
                         for nested_meta in list.nested.iter() {
                             match nested_meta {
                                 syn::NestedMeta::Meta(meta) => {
-                                    conf.apply_attrs(meta)?;
+                                    metas.push(meta.clone());
                                 }
                                 syn::NestedMeta::Literal(lit) => {
                                     Err(SynError::new(
@@ -543,5 +2732,115 @@ fn parse_attrs(
             }
         }
     }
-    Ok(conf)
+    Ok(metas)
+}
+
+/// Parses the content of a string literal in the attributes.
+///
+/// The lexing errors of the content, e.g. an unclosed delimiter, make the compiler panic, so they
+/// are checked first.
+fn parse_str<T: syn::parse::Parse>(input: &str) -> ParseResult<T> {
+    parse_str_with(T::parse, input)
+}
+
+fn parse_str_with<P: syn::parse::Parser>(parser: P, input: &str) -> ParseResult<P::Output> {
+    if !is_lexable(input) {
+        Err(SynError::new(
+            proc_macro2::Span::call_site(),
+            "failed to split the input into tokens",
+        ))?;
+    }
+    parser.parse_str(input)
+}
+
+fn is_lexable(input: &str) -> bool {
+    let chars = input.chars().collect::<Vec<_>>();
+    let is_ident_char = |c: char| c.is_xid_continue();
+    let mut delimiters = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let starts_token = |i: usize| i == 0 || !is_ident_char(chars[i - 1]);
+        match chars[i] {
+            '(' | '[' | '{' => delimiters.push(chars[i]),
+            ')' | ']' | '}' => {
+                let open = match chars[i] {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if delimiters.pop() != Some(open) {
+                    return false;
+                }
+            }
+            '"' => loop {
+                i += 1;
+                match chars.get(i) {
+                    None => return false,
+                    Some('\\') => i += 1,
+                    Some('"') => break,
+                    Some(_) => {}
+                }
+            },
+            // A raw string, such as `r#"..."#`, but not a raw identifier, such as `r#type`.
+            'r' if starts_token(i) || (chars[i - 1] == 'b' && starts_token(i - 1)) => {
+                let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+                if chars.get(i + 1 + hashes) == Some(&'"') {
+                    let end = ::std::iter::once('"')
+                        .chain(::std::iter::repeat('#').take(hashes))
+                        .collect::<Vec<_>>();
+                    match chars[i + 2 + hashes..]
+                        .windows(end.len())
+                        .position(|window| window == &end[..])
+                    {
+                        Some(pos) => i += 1 + hashes + pos + end.len(),
+                        None => return false,
+                    }
+                }
+            }
+            // A character literal, but not a lifetime.
+            '\'' => match (chars.get(i + 1), chars.get(i + 2)) {
+                (Some('\\'), _) => match chars
+                    .get(i + 3..)
+                    .and_then(|rest| rest.iter().position(|c| *c == '\''))
+                {
+                    Some(pos) => i += 3 + pos,
+                    None => return false,
+                },
+                (Some(_), Some('\'')) => i += 2,
+                _ => {}
+            },
+            '/' => match chars.get(i + 1) {
+                Some('/') => match chars[i..].iter().position(|c| *c == '\n') {
+                    Some(pos) => i += pos,
+                    None => break,
+                },
+                Some('*') => {
+                    let mut depth = 1;
+                    i += 2;
+                    while depth > 0 {
+                        match (chars.get(i), chars.get(i + 1)) {
+                            (None, _) => return false,
+                            (Some('/'), Some('*')) => {
+                                depth += 1;
+                                i += 1;
+                            }
+                            (Some('*'), Some('/')) => {
+                                depth -= 1;
+                                i += 1;
+                            }
+                            _ => {}
+                        }
+                        i += 1;
+                    }
+                    continue;
+                }
+                _ => {}
+            },
+            // Other characters out of the literals and the comments are unknown tokens.
+            c if !c.is_ascii() && !c.is_whitespace() && !is_ident_char(c) => return false,
+            _ => {}
+        }
+        i += 1;
+    }
+    delimiters.is_empty()
 }
@@ -0,0 +1,152 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Idle,
+    Running(u8),
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Idle
+    }
+}
+
+#[derive(Property, Default)]
+#[property(to(crate), take(crate), replace(crate))]
+struct Task {
+    name: String,
+    #[property(pred, toggle)]
+    done: bool,
+    #[property(bits)]
+    flags: u8,
+    #[property(set(disable), bits(suffix = ""))]
+    mode: u8,
+    #[property(update(type = "ref"), pred)]
+    notes: Vec<String>,
+    #[property(at, forward(len, is_empty))]
+    steps: Vec<u32>,
+    #[property(
+        matches(name = "is_idle", pattern = "State::Idle"),
+        matches(public, name = "is_running", pattern = "State::Running(_)")
+    )]
+    state: State,
+}
+
+#[test]
+fn get_set_and_mut() {
+    let mut task = Task::default();
+    task.set_name("task").set_flags(1u8);
+    task.mut_name().push('s');
+    assert_eq!(task.name(), "tasks");
+    assert_eq!(task.flags(), 1);
+}
+
+#[test]
+fn to_take_and_replace() {
+    let mut task = Task::default();
+    task.set_name("task");
+    assert_eq!(task.to_name(), "task");
+    assert_eq!(task.replace_name("new"), "task");
+    assert_eq!(task.take_name(), "new");
+    assert_eq!(task.name(), "");
+}
+
+#[derive(Property)]
+#[property(take(crate))]
+struct Borrowed<'a> {
+    name: &'a str,
+    age: &'a u8,
+    parsed: Result<u8, String>,
+}
+
+#[test]
+fn take_skips_fields_without_default() {
+    let age = 1;
+    let mut borrowed = Borrowed {
+        name: "name",
+        age: &age,
+        parsed: Ok(1),
+    };
+    assert_eq!(borrowed.take_name(), "name");
+    assert_eq!(borrowed.name(), "");
+    assert_eq!(*borrowed.age(), 1);
+    assert_eq!(borrowed.parsed(), Ok(&1));
+}
+
+#[test]
+fn pred_and_toggle() {
+    let mut task = Task::default();
+    assert!(!task.is_done());
+    assert!(!task.has_notes());
+    task.toggle_done();
+    task.update_notes(|notes| notes.push("note".to_owned()))
+        .update_notes(|notes| notes.push("more".to_owned()));
+    assert!(task.is_done());
+    assert!(task.has_notes());
+    assert_eq!(task.notes().len(), 2);
+    task.toggle_done();
+    assert!(!task.done());
+}
+
+#[test]
+fn bits() {
+    let mut task = Task::default();
+    task.set_flags_bits(0b101);
+    assert!(task.has_flags_bits(0b100));
+    task.clear_flags_bits(0b100);
+    assert!(!task.has_flags_bits(0b100));
+    assert_eq!(task.flags(), 0b001);
+    task.set_mode(0b11);
+    task.clear_mode(0b01);
+    assert!(task.has_mode(0b10));
+    assert_eq!(task.mode(), 0b10);
+}
+
+#[test]
+fn at_and_forward() {
+    let mut task = Task::default();
+    assert!(task.is_empty());
+    task.set_steps(vec![1u32, 2]);
+    assert_eq!(task.len(), 2);
+    assert_eq!(task.steps_at(1), Some(&2));
+    assert_eq!(task.steps_at(2), None);
+}
+
+#[test]
+fn matches() {
+    let mut task = Task::default();
+    assert!(task.is_idle());
+    task.set_state(State::Running(1));
+    assert!(task.is_running());
+    assert!(!task.is_idle());
+}
+
+trait HasName {
+    fn name(&self) -> &str;
+}
+
+#[derive(Property, Default)]
+struct Legacy {
+    #[deprecated]
+    #[property(get(trait = "HasName"))]
+    name: String,
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_field_in_trait() {
+    let mut legacy = Legacy::default();
+    legacy.set_name("legacy");
+    assert_eq!(HasName::name(&legacy), "legacy");
+}
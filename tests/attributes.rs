@@ -0,0 +1,116 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(set(prefix = "with_"))]
+#[property(get(public), export_names)]
+struct Split {
+    #[property(set(suffix = "_v"))]
+    a: u32,
+    #[property(get(prefix = "get_"))]
+    #[property(get(suffix = "_v"), mut(disable))]
+    b: u32,
+}
+
+#[test]
+fn split_attributes() {
+    let mut split = Split::default();
+    split.with_a_v(1u32).with_b(2u32);
+    assert_eq!(split.a(), 1);
+    assert_eq!(split.get_b_v(), 2);
+    assert_eq!(
+        Split::_PROPERTY_METHODS,
+        &["a", "with_a_v", "mut_a", "get_b_v", "with_b"]
+    );
+}
+
+#[derive(Property, Default)]
+struct Forwarded {
+    #[property(get(attr = "must_use"), set(attr = "doc = \"Sets `(`.\""))]
+    #[property(get(attr = "doc(hidden)"))]
+    name: String,
+}
+
+#[test]
+fn forwarded_attributes() {
+    let mut forwarded = Forwarded::default();
+    forwarded.set_name("name");
+    assert_eq!(forwarded.name(), "name");
+}
+
+#[derive(Property, Default)]
+#[property(export_names)]
+struct Configured {
+    #[property(mut(public, cfg = "test"))]
+    tested: u32,
+    #[property(get(cfg = "not(test)"), mut(disable))]
+    hidden: u32,
+}
+
+#[test]
+fn cfg_methods() {
+    let mut configured = Configured::default();
+    *configured.mut_tested() += 1;
+    assert_eq!(configured.tested(), 1);
+    configured.set_hidden(2u32);
+    assert_eq!(configured.hidden, 2);
+    // The names of the methods are listed whatever their configurations are.
+    assert_eq!(
+        Configured::_PROPERTY_METHODS,
+        &["tested", "set_tested", "mut_tested", "hidden", "set_hidden"]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(mut(disable), export_names)]
+struct Bare {
+    #[property(mut, take)]
+    count: u32,
+    #[property(get)]
+    name: String,
+}
+
+#[test]
+fn bare_words() {
+    let mut bare = Bare::default();
+    *bare.mut_count() += 1;
+    assert_eq!(bare.take_count(), 1);
+    assert_eq!(bare.count(), 0);
+    assert_eq!(
+        Bare::_PROPERTY_METHODS,
+        &[
+            "count",
+            "set_count",
+            "mut_count",
+            "take_count",
+            "name",
+            "set_name"
+        ]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(export_names)]
+struct Skipped {
+    #[property(skip(set, mut))]
+    a: u32,
+    #[property(skip(set, mut_))]
+    b: u32,
+}
+
+#[test]
+fn skip_methods() {
+    let skipped = Skipped::default();
+    assert_eq!(skipped.a(), 0);
+    assert_eq!(skipped.b(), 0);
+    assert_eq!(Skipped::_PROPERTY_METHODS, &["a", "b"]);
+}
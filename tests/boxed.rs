@@ -0,0 +1,115 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Packet {
+    payload: Box<[u8]>,
+    #[property(get(type = "ref"))]
+    raw: Box<[u8]>,
+}
+
+#[test]
+#[allow(clippy::borrowed_box)]
+fn boxed_slice() {
+    let mut packet = Packet::default();
+    packet.set_payload(vec![1, 2, 3].into_boxed_slice());
+    let payload: &[u8] = packet.payload();
+    assert_eq!(payload, &[1, 2, 3]);
+    let raw: &Box<[u8]> = packet.raw();
+    assert!(raw.is_empty());
+}
+
+#[derive(Property, Default)]
+struct Note {
+    title: Option<Box<str>>,
+    body: Option<Box<[u8]>>,
+    #[property(get(type = "ref"))]
+    raw: Option<Box<str>>,
+}
+
+#[test]
+fn optional_boxes() {
+    let mut note = Note::default();
+    assert_eq!(note.title(), None);
+    assert_eq!(note.body(), None);
+    note.set_title(Box::<str>::from("title"))
+        .set_body(vec![1u8].into_boxed_slice());
+    let title: Option<&str> = note.title();
+    let body: Option<&[u8]> = note.body();
+    assert_eq!(title, Some("title"));
+    assert_eq!(body, Some(&[1u8][..]));
+    assert!(note.raw().is_none());
+}
+
+trait Counter {
+    fn count(&self) -> u32;
+    fn bump(&mut self);
+}
+
+impl Counter for u32 {
+    fn count(&self) -> u32 {
+        *self
+    }
+    fn bump(&mut self) {
+        *self += 1;
+    }
+}
+
+#[derive(Property)]
+struct Handlers {
+    debug: Box<dyn std::fmt::Debug>,
+    counter: Box<dyn Counter>,
+}
+
+#[test]
+fn boxed_trait_objects() {
+    let mut handlers = Handlers {
+        debug: Box::new(1u8),
+        counter: Box::new(0u32),
+    };
+    let debug: &dyn std::fmt::Debug = handlers.debug();
+    assert_eq!(format!("{:?}", debug), "1");
+    handlers.mut_counter().bump();
+    let counter: &dyn Counter = handlers.counter();
+    assert_eq!(counter.count(), 1);
+    handlers.set_debug(Box::new("debug") as Box<dyn std::fmt::Debug>);
+    assert_eq!(format!("{:?}", handlers.debug()), "\"debug\"");
+}
+
+#[derive(Property)]
+struct Boxes {
+    number: Box<u32>,
+    name: Box<str>,
+    counter: Box<dyn Counter>,
+    #[property(get(type = "ref"))]
+    raw_number: Box<u32>,
+}
+
+#[test]
+#[allow(clippy::borrowed_box)]
+fn box_getters() {
+    let mut boxes = Boxes {
+        number: Box::new(1),
+        name: "name".into(),
+        counter: Box::new(2u32),
+        raw_number: Box::new(3),
+    };
+    **boxes.mut_number() += 1;
+    let number: &u32 = boxes.number();
+    let name: &str = boxes.name();
+    let counter: &dyn Counter = boxes.counter();
+    let raw_number: &Box<u32> = boxes.raw_number();
+    assert_eq!(*number, 2);
+    assert_eq!(name, "name");
+    assert_eq!(counter.count(), 2);
+    assert_eq!(**raw_number, 3);
+}
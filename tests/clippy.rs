@@ -0,0 +1,38 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(clippy::all, clippy::pedantic)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(
+    get(public),
+    set(public),
+    pred(public),
+    update(public),
+    visibility = "public",
+    clippy_allow
+)]
+pub struct Builder {
+    #[property(push(public, type = "ref"))]
+    items: Vec<u32>,
+    enabled: bool,
+    #[property(set(type = "own", must_use = "false"))]
+    name: String,
+}
+
+#[test]
+fn clippy_allow() {
+    let mut builder = Builder::default();
+    builder.set_enabled(true).push_items(1_u32);
+    let builder = builder.set_name("name");
+    assert!(builder.is_enabled());
+    assert_eq!(builder.items(), &[1]);
+    assert_eq!(builder.name(), "name");
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Queue {
+    #[property(forward(clear))]
+    jobs: VecDeque<u32>,
+}
+
+#[test]
+fn vec_deque() {
+    let mut queue = Queue::default();
+    queue.set_jobs(vec![1u32, 2, 3]);
+    queue.mut_jobs().push_front(0);
+    assert_eq!(queue.jobs().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    queue.clear();
+    assert_eq!(queue.jobs().count(), 0);
+}
+
+#[derive(Property, Default)]
+struct Tags {
+    #[property(get(type = "contains"))]
+    names: HashSet<String>,
+}
+
+#[test]
+fn hash_set_contains() {
+    let mut tags = Tags::default();
+    tags.mut_names().insert("a".to_owned());
+    assert!(tags.has_names(&"a".to_owned()));
+    assert!(!tags.has_names(&"b".to_owned()));
+}
+
+#[derive(Property, Default)]
+struct Scheduler {
+    #[property(forward(clear, len))]
+    priorities: BinaryHeap<u32>,
+}
+
+#[test]
+fn binary_heap_peek() {
+    let mut scheduler = Scheduler::default();
+    assert_eq!(scheduler.priorities(), None);
+    scheduler.set_priorities(vec![2u32, 5, 1]);
+    scheduler.mut_priorities().push(3);
+    assert_eq!(scheduler.priorities(), Some(&5));
+    assert_eq!(scheduler.len(), 4);
+    scheduler.clear();
+    assert_eq!(scheduler.priorities(), None);
+}
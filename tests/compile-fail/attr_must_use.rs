@@ -0,0 +1,13 @@
+#![deny(unused_must_use)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+pub struct Pet {
+    #[property(get(attr = "must_use"))]
+    name: String,
+}
+
+fn main() {
+    Pet::default().name();
+}
@@ -0,0 +1,8 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    name: Box,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::cell::Cell;
+
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(type = "replace_if_changed"))]
+    name: Cell<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(prefix = "2")]
+pub struct Pet {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(prefix = "1_"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub enum Pet {
+    Cat,
+    Dog,
+}
+
+fn main() {}
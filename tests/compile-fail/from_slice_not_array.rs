@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(type = "from_slice"))]
+    name: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(attr = "must_use("))]
+    name: String,
+}
+
+fn main() {}
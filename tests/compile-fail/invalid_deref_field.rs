@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(deref = "a²")]
+pub struct Pet {
+    name: String,
+}
+
+fn main() {}
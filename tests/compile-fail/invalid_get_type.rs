@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(type = "unknown"))]
+    name: String,
+}
+
+fn main() {}
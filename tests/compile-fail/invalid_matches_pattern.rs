@@ -0,0 +1,15 @@
+use property::Property;
+
+#[derive(Clone, Copy)]
+pub enum State {
+    Idle,
+    Running,
+}
+
+#[derive(Property)]
+pub struct Task {
+    #[property(matches(name = "is_idle", pattern = "State::Idle => true"))]
+    state: State,
+}
+
+fn main() {}
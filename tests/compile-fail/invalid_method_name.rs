@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(name = "1name"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(get(prefix = "-"))]
+pub struct Pet {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(rename = "a²")]
+    name: String,
+}
+
+fn main() {}
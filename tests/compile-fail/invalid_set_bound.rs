@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(bound = "unknown"))]
+    name: String,
+}
+
+fn main() {}
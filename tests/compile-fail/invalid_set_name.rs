@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(name = "set name"))]
+    name: String,
+}
+
+fn main() {}
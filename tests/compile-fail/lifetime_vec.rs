@@ -0,0 +1,8 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    names: Vec<'static>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(prefix = "²")]
+pub struct Pet {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(name = "a²"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(type = "opt"))]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![deny(unused_must_use)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(set(type = "own"))]
+pub struct Pet {
+    name: String,
+    #[property(set(must_use = "false"))]
+    age: u8,
+}
+
+fn main() {
+    Pet::default().set_age(1u8);
+    Pet::default().set_name("name");
+}
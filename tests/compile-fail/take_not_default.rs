@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(take)]
+    age: Result<u8, String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(type = "take_replace"))]
+    name: String,
+}
+
+fn main() {}
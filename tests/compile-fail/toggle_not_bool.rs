@@ -0,0 +1,11 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(toggle)]
+pub struct Pet {
+    hungry: bool,
+    #[property(toggle)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(unknown)]
+pub struct Pet {
+    name: String,
+}
+
+fn main() {}
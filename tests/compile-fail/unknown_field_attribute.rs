@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(unknown))]
+    name: String,
+}
+
+fn main() {}
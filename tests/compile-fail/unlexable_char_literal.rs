@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(set(arg = "&str", convert = "'\\"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use property::Property;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Cell;
+
+mod grid {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Cell;
+}
+
+#[derive(Property, Default)]
+pub struct Board {
+    current: Cell,
+    next: grid::Cell,
+}
+
+fn main() {
+    let mut board = Board::default();
+    board.set_current(Cell).set_next(grid::Cell);
+    assert_eq!(board.current(), &Cell);
+    assert_eq!(board.next(), &grid::Cell);
+}
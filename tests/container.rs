@@ -0,0 +1,405 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(skip_prefix = "tmp_", export_names)]
+struct Cache {
+    key: String,
+    tmp_buffer: Vec<u8>,
+    tmp_hits: u32,
+}
+
+#[test]
+fn skip_prefix() {
+    let mut cache = Cache::default();
+    cache.set_key("key");
+    assert_eq!(cache.key(), "key");
+    assert_eq!(Cache::_PROPERTY_METHODS, &["key", "set_key", "mut_key"]);
+}
+
+#[derive(Property, Default)]
+#[property(prefix = "inner_", take(crate), export_names)]
+struct Inner {
+    value: u32,
+    #[property(set(suffix = "_now"))]
+    label: String,
+}
+
+#[test]
+fn container_prefix() {
+    let mut inner = Inner::default();
+    inner.set_inner_value(1u32).set_inner_label_now("label");
+    *inner.mut_inner_value() += 1;
+    assert_eq!(inner.inner_value(), 2);
+    assert_eq!(inner.take_inner_label(), "label");
+    assert_eq!(
+        Inner::_PROPERTY_METHODS,
+        &[
+            "inner_value",
+            "set_inner_value",
+            "mut_inner_value",
+            "take_inner_value",
+            "inner_label",
+            "set_inner_label_now",
+            "mut_inner_label",
+            "take_inner_label",
+        ]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(group_by = "kind", take(crate), export_names)]
+struct Grouped {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn group_by_kind() {
+    assert_eq!(
+        Grouped::_PROPERTY_METHODS,
+        &["a", "b", "set_a", "set_b", "mut_a", "mut_b", "take_a", "take_b"]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(set(type = "own"))]
+struct Request {
+    url: String,
+    retries: u8,
+    headers: Vec<String>,
+    #[property(set(type = "ref"))]
+    timeout: Option<u32>,
+}
+
+#[test]
+fn own_setters_from_container() {
+    let mut request = Request::default()
+        .set_url("https://example.com")
+        .set_retries(3u8)
+        .set_headers(vec!["accept"]);
+    request.set_timeout(10u32).set_timeout(None);
+    assert_eq!(request.url(), "https://example.com");
+    assert_eq!(request.retries(), 3);
+    assert_eq!(request.headers(), &["accept".to_owned()]);
+    assert_eq!(request.timeout(), None);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Property, Default)]
+#[property(get(copy))]
+struct Shape {
+    origin: Point,
+    size: (u32, u32),
+    #[property(get(type = "ref"))]
+    name: String,
+    #[property(get(type = "ref"))]
+    corner: Point,
+}
+
+#[test]
+fn copy_getters_from_container() {
+    let mut shape = Shape::default();
+    shape.set_origin(Point { x: 1, y: 2 }).set_name("shape");
+    let origin: Point = shape.origin();
+    let size: (u32, u32) = shape.size();
+    let name: &String = shape.name();
+    let corner: &Point = shape.corner();
+    assert_eq!(origin, Point { x: 1, y: 2 });
+    assert_eq!(size, (0, 0));
+    assert_eq!(name, "shape");
+    assert_eq!(corner, &Point::default());
+}
+
+#[derive(Property, Debug, PartialEq)]
+#[property(default, new(public))]
+struct Empty {}
+
+#[test]
+fn empty_struct() {
+    assert_eq!(Empty::default(), Empty {});
+    assert_eq!(Empty::new(), Empty {});
+}
+
+#[derive(Property, Default)]
+#[property(prefix = "größe_")]
+struct Maß {
+    wert: u8,
+    #[property(get(prefix = "ä_"))]
+    höhe: u8,
+}
+
+#[test]
+fn unicode_names() {
+    let mut maß = Maß::default();
+    maß.set_größe_wert(1u8).set_größe_höhe(2u8);
+    assert_eq!(maß.größe_wert(), 1);
+    assert_eq!(maß.ä_größe_höhe(), 2);
+}
+
+#[derive(Property, Default)]
+#[property(prefix = "inner_", take(crate), export_names)]
+struct Renamed {
+    #[property(rename = "label")]
+    old_label: String,
+}
+
+#[test]
+fn rename() {
+    let mut renamed = Renamed::default();
+    renamed.set_label("label");
+    renamed.mut_label().push('s');
+    assert_eq!(renamed.label(), "labels");
+    assert_eq!(renamed.take_label(), "labels");
+    assert_eq!(
+        Renamed::_PROPERTY_METHODS,
+        &["label", "set_label", "mut_label", "take_label"]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(deref = "raw", deref_mut = "raw")]
+#[repr(transparent)]
+struct Bytes {
+    raw: Vec<u8>,
+}
+
+#[derive(Property, Default)]
+#[property(deref = "name")]
+struct Label {
+    name: String,
+}
+
+#[test]
+fn deref() {
+    let mut bytes = Bytes::default();
+    bytes.push(1);
+    bytes.extend_from_slice(&[2, 3]);
+    assert_eq!(bytes.len(), 3);
+    assert_eq!(&bytes[..], &[1, 2, 3]);
+    let mut label = Label::default();
+    label.set_name("label");
+    assert_eq!(label.to_uppercase(), "LABEL");
+    let name: &String = &label;
+    assert_eq!(name, "label");
+}
+
+#[derive(Property, Default)]
+#[property(field_names)]
+struct Column {
+    user_id: u32,
+    r#type: String,
+    #[property(skip)]
+    cache: Vec<u8>,
+}
+
+const COLUMNS: [&str; 2] = [Column::USER_ID_FIELD, Column::TYPE_FIELD];
+
+#[test]
+fn field_names() {
+    assert_eq!(COLUMNS, ["user_id", "type"]);
+}
+
+#[derive(Property, Default)]
+#[property(skip_pub_fields, export_names)]
+struct Mixed {
+    pub id: u32,
+    pub(crate) name: String,
+    secret: String,
+    #[property(get(crate))]
+    pub score: u8,
+}
+
+#[test]
+fn skip_pub_fields() {
+    let mut mixed = Mixed::default();
+    mixed.set_id(1u32).set_secret("secret");
+    assert_eq!(mixed.id, 1);
+    assert_eq!(mixed.secret(), "secret");
+    assert_eq!(mixed.score(), 0);
+    assert_eq!(
+        Mixed::_PROPERTY_METHODS,
+        &[
+            "set_id",
+            "mut_id",
+            "set_name",
+            "mut_name",
+            "secret",
+            "set_secret",
+            "mut_secret",
+            "score",
+            "set_score",
+            "mut_score",
+        ]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(
+    computed(name = "area", expr = "self.width * self.height", type_out = "u32"),
+    computed(
+        public,
+        name = "label",
+        expr = "format!(\"{}x{}\", self.width, self.height)",
+        type_out = "String"
+    )
+)]
+struct Rect {
+    width: u32,
+    height: u32,
+}
+
+#[test]
+fn computed() {
+    let mut rect = Rect::default();
+    rect.set_width(3u32).set_height(4u32);
+    assert_eq!(rect.area(), 12);
+    assert_eq!(rect.label(), "3x4");
+}
+
+#[derive(Property, Debug, PartialEq)]
+#[property(default, new(public, name = "create"))]
+struct Connection {
+    host: String,
+    port: u16,
+    tags: Vec<String>,
+    timeout: Option<u32>,
+    secure: bool,
+    #[property(skip)]
+    retries: u8,
+}
+
+#[test]
+fn new_and_default() {
+    let connection = Connection::default();
+    assert_eq!(connection.host(), "");
+    assert_eq!(connection.port(), 0);
+    assert!(connection.tags().is_empty());
+    assert_eq!(connection.timeout(), None);
+    assert!(!connection.secure());
+    assert_eq!(connection.retries, 0);
+    let connection = Connection::create("host", 80u16, vec!["a".to_owned()], 10u32, true);
+    assert_eq!(connection.host(), "host");
+    assert_eq!(connection.port(), 80);
+    assert_eq!(connection.tags(), &["a".to_owned()]);
+    assert_eq!(connection.timeout(), Some(&10));
+    assert!(connection.secure());
+    assert_eq!(connection.retries, 0);
+}
+
+#[derive(Property, Default)]
+#[property(rename_all = "camelCase", export_names)]
+struct CamelCase {
+    max_retries: u8,
+    #[property(get(name = "raw_name"))]
+    user_name: String,
+    #[property(set(prefix = "put_"), get(suffix = "_value"))]
+    retry_delay: u8,
+}
+
+#[derive(Property, Default)]
+#[property(rename_all = "SCREAMING_SNAKE_CASE", take(crate), export_names)]
+struct Screaming {
+    max_retries: u8,
+}
+
+#[test]
+fn rename_all() {
+    let mut camel_case = CamelCase::default();
+    camel_case.setMaxRetries(1u8).setUserName("name");
+    *camel_case.mutMaxRetries() += 1;
+    assert_eq!(camel_case.maxRetries(), 2);
+    assert_eq!(camel_case.raw_name(), "name");
+    camel_case.put_retry_delay(3u8);
+    assert_eq!(camel_case.retry_delay_value(), 3);
+    assert_eq!(
+        CamelCase::_PROPERTY_METHODS,
+        &[
+            "maxRetries",
+            "setMaxRetries",
+            "mutMaxRetries",
+            "raw_name",
+            "setUserName",
+            "mutUserName",
+            "retry_delay_value",
+            "put_retry_delay",
+            "mutRetryDelay",
+        ]
+    );
+    assert_eq!(
+        Screaming::_PROPERTY_METHODS,
+        &[
+            "MAX_RETRIES",
+            "SET_MAX_RETRIES",
+            "MUT_MAX_RETRIES",
+            "TAKE_MAX_RETRIES",
+        ]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(accessor_prefix(get = "get_", set = "put_", mut = "edit_"))]
+struct Prefixed {
+    #[property(get(alias = "value"))]
+    amount: u32,
+    #[property(set(prefix = "with_"))]
+    label: String,
+}
+
+#[test]
+#[allow(deprecated)]
+fn accessor_prefix_and_alias() {
+    let mut prefixed = Prefixed::default();
+    prefixed.put_amount(1u32).with_label("label");
+    *prefixed.edit_amount() += 1;
+    assert_eq!(prefixed.get_amount(), 2);
+    assert_eq!(prefixed.value(), 2);
+    assert_eq!(prefixed.get_label(), "label");
+}
+
+#[derive(Property, Default)]
+#[property(get(disable), set(disable), mut(disable), export_names)]
+#[property(pred, toggle, bits, push, at)]
+struct Inherited {
+    done: bool,
+    flags: u8,
+    names: Vec<String>,
+}
+
+#[test]
+fn inherited_methods_skip_unfit_fields() {
+    let mut inherited = Inherited::default();
+    inherited.toggle_done();
+    inherited.set_flags_bits(1);
+    inherited.push_names("name");
+    assert!(inherited.is_done());
+    assert!(inherited.has_flags_bits(1));
+    assert_eq!(inherited.names_at(0).map(String::as_str), Some("name"));
+    assert_eq!(
+        Inherited::_PROPERTY_METHODS,
+        &[
+            "is_done",
+            "toggle_done",
+            "set_flags_bits",
+            "clear_flags_bits",
+            "has_flags_bits",
+            "has_names",
+            "push_names",
+            "names_at",
+        ]
+    );
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
+
+use property::Property;
+
+mod a {
+    #[derive(Clone, Copy, Default, PartialEq, Debug)]
+    pub struct Id(pub u32);
+}
+
+mod b {
+    #[derive(Default, PartialEq, Debug)]
+    pub struct Id(pub u32);
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Version(u32);
+
+#[derive(Property)]
+#[property(copy_types = "a::Id, Version")]
+struct Record {
+    timeout: Duration,
+    addr: IpAddr,
+    id: a::Id,
+    other_id: b::Id,
+    version: Version,
+}
+
+#[test]
+fn copy_types() {
+    let mut record = Record {
+        timeout: Duration::from_secs(1),
+        addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        id: a::Id(1),
+        other_id: b::Id(2),
+        version: Version(3),
+    };
+    record.set_timeout(Duration::from_secs(2));
+    let timeout: Duration = record.timeout();
+    let addr: IpAddr = record.addr();
+    let id: a::Id = record.id();
+    let other_id: &b::Id = record.other_id();
+    let version: Version = record.version();
+    assert_eq!(timeout, Duration::from_secs(2));
+    assert!(addr.is_loopback());
+    assert_eq!(id, a::Id(1));
+    assert_eq!(other_id, &b::Id(2));
+    assert_eq!(version, Version(3));
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use property::Property;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Property)]
+#[property(dump, skip_prefix = "tmp_")]
+struct Entry {
+    name: String,
+    level: Level,
+    tags: Vec<u8>,
+    #[property(skip)]
+    cache: Vec<u8>,
+    tmp_count: u32,
+    marker: PhantomData<u8>,
+    #[property(redact)]
+    secret: Option<String>,
+}
+
+#[test]
+fn dump_fields() {
+    let entry = Entry {
+        name: "entry".to_owned(),
+        level: Level::High,
+        tags: vec![1, 2],
+        cache: vec![3],
+        tmp_count: 4,
+        marker: PhantomData,
+        secret: Some("secret".to_owned()),
+    };
+    assert_eq!(
+        entry.dump_fields(),
+        vec![
+            ("name", "\"entry\"".to_owned()),
+            ("level", "High".to_owned()),
+            ("tags", "[1, 2]".to_owned()),
+            ("secret", "<redacted>".to_owned()),
+        ]
+    );
+}
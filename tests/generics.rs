@@ -0,0 +1,117 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Wrapper<T: Clone = u32> {
+    inner: T,
+    #[property(get(type = "clone"))]
+    items: Vec<T>,
+}
+
+#[test]
+fn default_type_parameter() {
+    let mut wrapper: Wrapper = Wrapper::default();
+    wrapper.set_inner(1u32).set_items(vec![2u32]);
+    assert_eq!(wrapper.inner(), &1);
+    assert_eq!(wrapper.items(), vec![2]);
+    let mut wrapper = Wrapper::<String>::default();
+    wrapper.set_inner("inner");
+    assert_eq!(wrapper.inner(), "inner");
+}
+
+#[derive(Property)]
+struct Buf<const N: usize> {
+    #[property(mut(type = "slice"))]
+    data: [u8; N],
+    #[property(get(type = "copy"))]
+    copy: [u8; N],
+    #[property(get(type = "head", n = "2"))]
+    head: [u8; N],
+}
+
+#[test]
+fn const_generic_array() {
+    let mut buf = Buf::<3> {
+        data: [0; 3],
+        copy: [1, 2, 3],
+        head: [4, 5, 6],
+    };
+    buf.set_data([1, 2, 3]);
+    buf.mut_data()[0] = 7;
+    let data: &[u8] = buf.data();
+    assert_eq!(data, &[7, 2, 3]);
+    let copy: [u8; 3] = buf.copy();
+    assert_eq!(copy, [1, 2, 3]);
+    assert_eq!(buf.head(), &[4, 5]);
+    let buf = Buf::<1> {
+        data: [0],
+        copy: [0],
+        head: [8],
+    };
+    assert_eq!(buf.head(), &[8]);
+}
+
+#[derive(Property, Default)]
+struct Named<T> {
+    #[property(set(type = "extend"))]
+    values: Vec<T>,
+    first: Option<T>,
+}
+
+#[test]
+fn type_parameter_named_t() {
+    let mut named = Named::<u64>::default();
+    named.set_values(vec![1u32, 2]).set_first(3u64);
+    named.set_values(vec![4u8]);
+    assert_eq!(named.values(), &[1, 2, 4]);
+    assert_eq!(named.first(), Some(&3));
+}
+
+#[derive(Property)]
+#[property(export_names)]
+struct Tagged<T> {
+    id: u32,
+    phantom: PhantomData<T>,
+    #[property(get(public))]
+    kept: PhantomData<T>,
+}
+
+#[test]
+fn phantom_data_is_skipped() {
+    let tagged = Tagged::<String> {
+        id: 1,
+        phantom: PhantomData,
+        kept: PhantomData,
+    };
+    assert_eq!(tagged.id(), 1);
+    let _: PhantomData<String> = tagged.kept();
+    assert_eq!(
+        Tagged::<String>::_PROPERTY_METHODS,
+        &["id", "set_id", "mut_id", "kept", "set_kept", "mut_kept"]
+    );
+}
+
+#[derive(Property)]
+struct W<T: Copy> {
+    #[property(get(type = "copy"))]
+    val: T,
+}
+
+#[test]
+fn copy_getter_of_type_parameter() {
+    let mut w = W { val: 1u8 };
+    w.set_val(2u8);
+    let val: u8 = w.val();
+    assert_eq!(val, 2);
+}
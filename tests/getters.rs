@@ -0,0 +1,146 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(take(crate))]
+struct Pair {
+    small: (u8, u8),
+    mixed: (String, u32),
+}
+
+#[test]
+fn tuples() {
+    let mut pair = Pair::default();
+    pair.set_small((1u8, 2u8)).set_mixed(("a".to_owned(), 3u32));
+    let small: (u8, u8) = pair.small();
+    let mixed: &(String, u32) = pair.mixed();
+    assert_eq!(small, (1, 2));
+    assert_eq!(mixed, &("a".to_owned(), 3));
+    assert_eq!(pair.take_small(), (1, 2));
+    assert_eq!(pair.small(), (0, 0));
+}
+
+#[derive(Property)]
+#[property(export_names)]
+struct Outcome {
+    result: Result<u32, String>,
+}
+
+#[test]
+fn result_getter() {
+    let mut outcome = Outcome { result: Ok(1) };
+    let result: Result<&u32, &String> = outcome.result();
+    assert_eq!(result, Ok(&1));
+    outcome.set_result(Err("error".to_owned()));
+    assert_eq!(outcome.result(), Err(&"error".to_owned()));
+    assert_eq!(
+        Outcome::_PROPERTY_METHODS,
+        &["result", "set_result", "mut_result"]
+    );
+}
+
+#[derive(Property, Default)]
+#[property(array_copy_threshold = 4)]
+struct Arrays {
+    below: [u8; 3],
+    at: [u16; 4],
+    above: [u8; 5],
+    names: [String; 2],
+}
+
+#[test]
+fn array_copy_threshold() {
+    let mut arrays = Arrays::default();
+    arrays.set_below([1, 2, 3]).set_at([4, 5, 6, 7]);
+    let below: [u8; 3] = arrays.below();
+    let at: [u16; 4] = arrays.at();
+    let above: &[u8] = arrays.above();
+    let names: &[String] = arrays.names();
+    assert_eq!(below, [1, 2, 3]);
+    assert_eq!(at, [4, 5, 6, 7]);
+    assert_eq!(above, &[0; 5]);
+    assert_eq!(names.len(), 2);
+}
+
+#[derive(Property)]
+struct Parts {
+    #[property(into)]
+    head: String,
+    #[property(into)]
+    body: Vec<u8>,
+}
+
+#[test]
+fn into_field() {
+    let parts = Parts {
+        head: "head".to_owned(),
+        body: vec![1],
+    };
+    assert_eq!(parts.into_head(), "head");
+    let parts = Parts {
+        head: "head".to_owned(),
+        body: vec![1],
+    };
+    assert_eq!(parts.into_body(), vec![1]);
+}
+
+#[derive(Property, Default)]
+struct Labels {
+    #[property(get(type = "iter"))]
+    names: Vec<Box<str>>,
+}
+
+#[test]
+fn iter_yields_str() {
+    let mut labels = Labels::default();
+    labels.set_names(vec![Box::<str>::from("a"), Box::from("b")]);
+    let names: Vec<&str> = labels.names().collect();
+    assert_eq!(names, vec!["a", "b"]);
+}
+
+#[derive(Debug, PartialEq)]
+struct LookupError;
+
+#[derive(Property, Default)]
+struct Fallible {
+    #[property(get(type = "ok"))]
+    id: u32,
+    #[property(get(type = "ok", err = "LookupError"))]
+    name: String,
+}
+
+#[test]
+fn ok_getters() {
+    let fallible = Fallible::default();
+    let id: Result<&u32, std::convert::Infallible> = fallible.id();
+    let name: Result<&String, LookupError> = fallible.name();
+    assert_eq!(id, Ok(&0));
+    assert_eq!(name, Ok(&String::new()));
+}
+
+#[derive(Property, Default)]
+struct Borrowed {
+    #[property(get(type = "borrow", type_out = "str"))]
+    name: String,
+    #[property(get(type = "borrow", type_out = "std::path::Path"))]
+    path: std::path::PathBuf,
+}
+
+#[test]
+fn borrow_getters() {
+    let mut borrowed = Borrowed::default();
+    borrowed.set_name("name").set_path("/tmp");
+    let name: &str = borrowed.name();
+    let path: &std::path::Path = borrowed.path();
+    assert_eq!(name, "name");
+    assert_eq!(path, std::path::Path::new("/tmp"));
+}
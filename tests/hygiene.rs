@@ -0,0 +1,81 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+#[no_implicit_prelude]
+mod bare {
+    #[derive(::property::Property)]
+    #[property(default, new(public), get(public), set(public), take, replace)]
+    pub struct Record {
+        name: ::std::string::String,
+        tags: ::std::vec::Vec<::std::string::String>,
+        parent: ::std::option::Option<u32>,
+        count: u32,
+        enabled: bool,
+    }
+
+    #[derive(::property::Property, Default)]
+    #[property(dump, field_names)]
+    pub struct Options {
+        #[property(set(bound = "as_ref"), pred, redact)]
+        label: ::std::string::String,
+        #[property(set(type = "extend"), push, at, forward(len))]
+        ids: ::std::vec::Vec<u32>,
+        #[property(get(type = "iter"))]
+        names: ::std::vec::Vec<::std::boxed::Box<str>>,
+        #[property(toggle, update, into)]
+        flag: bool,
+        #[property(bits, set(type = "replace_if_changed"))]
+        mask: u8,
+        #[property(
+            get(type = "try_into", type_out = "u8"),
+            matches(name = "is_zero", pattern = "0")
+        )]
+        first: u32,
+        #[property(set(type = "opt"), mut(type = "option"))]
+        second: ::std::option::Option<u32>,
+    }
+}
+
+#[test]
+fn no_imports_needed() {
+    let mut record = bare::Record::default();
+    record.set_name("name").set_count(1u32).set_parent(Some(2));
+    assert_eq!(record.name(), "name");
+    assert_eq!(record.count(), 1);
+    assert_eq!(record.parent(), Some(&2));
+    assert_eq!(record.take_tags(), Vec::<String>::new());
+    let record = bare::Record::new("other".to_owned(), Vec::new(), None, 0u32, true);
+    assert!(record.enabled());
+}
+
+#[test]
+fn no_imports_needed_for_all_methods() {
+    let mut options = bare::Options::default();
+    options
+        .set_label("label")
+        .set_ids(vec![1u32, 2])
+        .set_names(vec![Box::<str>::from("a")])
+        .set_mask(1u8);
+    options.push_ids(3u32);
+    options.toggle_flag();
+    options.update_flag(|flag| *flag = !*flag);
+    options.set_mask_bits(2);
+    assert!(options.has_label());
+    assert_eq!(options.len(), 3);
+    assert_eq!(options.ids_at(2), Some(&3));
+    assert_eq!(options.names().collect::<Vec<_>>(), vec!["a"]);
+    assert_eq!(options.mask(), 3);
+    assert_eq!(options.first(), Ok(0));
+    assert!(options.is_zero());
+    assert!(!options.flag());
+    assert_eq!(bare::Options::LABEL_FIELD, "label");
+    assert_eq!(options.dump_fields()[0], ("label", "<redacted>".to_owned()));
+    assert!(!options.into_flag());
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Interior {
+    names: RefCell<Vec<String>>,
+    label: Cell<String>,
+    #[property(as = "copy", set(type = "replace_if_changed"))]
+    id: Cell<Id>,
+    count: Cell<u32>,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+struct Id(u32);
+
+#[test]
+fn ref_cell() {
+    let interior = Interior::default();
+    interior.set_names(vec!["a".to_owned()]);
+    interior.mut_names().push("b".to_owned());
+    assert_eq!(&*interior.names(), &["a", "b"]);
+}
+
+#[test]
+fn cell_of_non_copy_type() {
+    let interior = Interior::default();
+    interior.set_label("label");
+    let label: &Cell<String> = interior.label();
+    assert_eq!(label.take(), "label");
+}
+
+#[test]
+fn cell_of_copy_hinted_type() {
+    let interior = Interior::default();
+    assert_eq!(interior.set_id(Id(1)), Some(Id(0)));
+    assert_eq!(interior.set_id(Id(1)), None);
+    assert_eq!(interior.id(), Id(1));
+}
+
+#[test]
+fn cell_of_copy_type() {
+    let interior = Interior::default();
+    let shared = &interior;
+    shared.set_count(1u32).set_count(2u8);
+    let count: u32 = shared.count();
+    assert_eq!(count, 2);
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Buffer {
+    data: Vec<u8>,
+    len: Option<usize>,
+    head: [u8; 4],
+}
+
+#[test]
+fn no_std_fields() {
+    let mut buffer = Buffer::default();
+    buffer
+        .set_data(alloc::vec![1, 2])
+        .set_len(2)
+        .set_head([1; 4]);
+    assert_eq!(buffer.data(), &[1, 2]);
+    assert_eq!(buffer.len(), Some(&2));
+    assert_eq!(buffer.head(), &[1; 4]);
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Profile {
+    nickname: Option<String>,
+    age: Option<u8>,
+}
+
+#[test]
+fn set_none_some_and_raw_value() {
+    let mut profile = Profile::default();
+    profile.set_nickname("nick".to_owned()).set_age(Some(30));
+    assert_eq!(profile.nickname(), Some(&"nick".to_owned()));
+    assert_eq!(profile.age(), Some(&30));
+    profile.set_nickname(None).set_age(31);
+    assert_eq!(profile.nickname(), None);
+    assert_eq!(profile.age(), Some(&31));
+}
+
+#[derive(Property, Default)]
+#[property(take(crate))]
+struct Tags {
+    names: Option<Vec<String>>,
+}
+
+#[test]
+fn optional_vec() {
+    let mut tags = Tags::default();
+    assert_eq!(tags.names(), None);
+    tags.set_names(vec!["a".to_owned()]);
+    let names: Option<&[String]> = tags.names();
+    assert_eq!(names, Some(&["a".to_owned()][..]));
+    assert_eq!(tags.take_names(), Some(vec!["a".to_owned()]));
+    assert_eq!(tags.names(), None);
+}
+
+#[derive(Property, Default)]
+struct Limits {
+    #[property(set(type = "opt"))]
+    max: Option<u64>,
+    #[property(set(type = "opt", bound = "exact"))]
+    min: Option<u64>,
+}
+
+#[test]
+fn opt_setter() {
+    let mut limits = Limits::default();
+    limits.set_max(Some(1u32)).set_min(Some(2));
+    assert_eq!(limits.max(), Some(&1));
+    assert_eq!(limits.min(), Some(&2));
+    limits.set_max(None::<u64>).set_min(None);
+    assert_eq!(limits.max(), None);
+    assert_eq!(limits.min(), None);
+}
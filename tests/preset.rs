@@ -0,0 +1,61 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(preset = "readonly", export_names)]
+struct ReadOnly {
+    id: u32,
+    #[property(set(crate))]
+    name: String,
+}
+
+#[derive(Property, Default)]
+#[property(preset = "builder", export_names)]
+struct Builder {
+    id: u32,
+    name: String,
+}
+
+#[derive(Property, Default)]
+#[property(preset = "plain", export_names)]
+struct Plain {
+    id: u32,
+}
+
+#[test]
+fn readonly() {
+    let mut read_only = ReadOnly::default();
+    read_only.set_name("name");
+    assert_eq!(read_only.id(), 0);
+    assert_eq!(read_only.name(), "name");
+    assert_eq!(ReadOnly::_PROPERTY_METHODS, &["id", "name", "set_name"]);
+}
+
+#[test]
+fn builder() {
+    let builder = Builder::default().set_id(1u32).set_name("name");
+    assert_eq!(builder.id(), 1);
+    assert_eq!(builder.name(), "name");
+    assert_eq!(
+        Builder::_PROPERTY_METHODS,
+        &["id", "set_id", "mut_id", "name", "set_name", "mut_name"]
+    );
+}
+
+#[test]
+fn plain() {
+    let mut plain = Plain::default();
+    plain.set_id(1u32);
+    *plain.mut_id() += 1;
+    assert_eq!(plain.id(), 2);
+    assert_eq!(Plain::_PROPERTY_METHODS, &["id", "set_id", "mut_id"]);
+}
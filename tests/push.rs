@@ -0,0 +1,38 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(push(disable, type = "ref"))]
+struct Queue {
+    #[property(push)]
+    q: VecDeque<u32>,
+    #[property(push(type = "none"))]
+    v: Vec<String>,
+    len: usize,
+}
+
+#[test]
+fn push_chained() {
+    let mut queue = Queue::default();
+    queue.push_q(1u32).push_q(2u8);
+    assert_eq!(queue.q().collect::<Vec<_>>(), vec![&1, &2]);
+}
+
+#[test]
+fn push_without_return() {
+    let mut queue = Queue::default();
+    queue.push_v("a");
+    queue.push_v("b");
+    assert_eq!(queue.v(), &["a", "b"]);
+}
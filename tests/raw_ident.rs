@@ -0,0 +1,56 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(export_names)]
+struct Token {
+    r#type: u8,
+    r#fn: String,
+    r#async: bool,
+}
+
+#[test]
+fn raw_identifiers() {
+    let mut token = Token::default();
+    token.set_type(1u8).set_fn("main").set_async(true);
+    *token.mut_type() += 1;
+    assert_eq!(token.r#type(), 2);
+    assert_eq!(token.r#fn(), "main");
+    assert!(token.r#async());
+    assert_eq!(
+        Token::_PROPERTY_METHODS,
+        &[
+            "type",
+            "set_type",
+            "mut_type",
+            "fn",
+            "set_fn",
+            "mut_fn",
+            "async",
+            "set_async",
+            "mut_async",
+        ]
+    );
+}
+
+#[derive(Property, Default)]
+struct Item {
+    #[property(get(name = "type"), set(name = "match"))]
+    kind: u8,
+}
+
+#[test]
+fn keyword_method_names() {
+    let mut item = Item::default();
+    item.r#match(1u8);
+    assert_eq!(item.r#type(), 1);
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(dump)]
+struct Account {
+    #[property(redact)]
+    password: String,
+    #[property(redact = "****")]
+    token: Vec<u8>,
+    #[property(redact(placeholder = "<key>"))]
+    key: String,
+}
+
+#[test]
+fn placeholders_ignore_values() {
+    let mut account = Account::default();
+    assert_eq!(account.password_redacted(), "<redacted>");
+    assert_eq!(account.token_redacted(), "****");
+    assert_eq!(account.key_redacted(), "<key>");
+    account
+        .set_password("secret")
+        .set_token(vec![1, 2, 3])
+        .set_key("key");
+    assert_eq!(account.password_redacted(), "<redacted>");
+    assert_eq!(account.token_redacted(), "****");
+    assert_eq!(account.key_redacted(), "<key>");
+    assert_eq!(account.password(), "secret");
+}
+
+#[test]
+fn dump_shows_placeholders() {
+    let mut account = Account::default();
+    account.set_password("secret").set_token(vec![1]);
+    assert_eq!(
+        account.dump_fields(),
+        vec![
+            ("password", "<redacted>".to_owned()),
+            ("token", "****".to_owned()),
+            ("key", "<key>".to_owned()),
+        ]
+    );
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property)]
+struct View<'a> {
+    name: &'a str,
+    bytes: &'a [u8],
+}
+
+#[test]
+fn reference_fields() {
+    let data = [1u8, 2];
+    let (name, bytes) = {
+        let mut view = View {
+            name: "name",
+            bytes: &[],
+        };
+        view.set_bytes(&data[..]);
+        (view.name(), view.bytes())
+    };
+    assert_eq!(name, "name");
+    assert_eq!(bytes, &[1, 2]);
+}
+
+#[derive(Property)]
+struct Wrapper<'a> {
+    data: &'a [u8],
+}
+
+fn into_data(wrapper: Wrapper<'_>) -> &[u8] {
+    wrapper.data()
+}
+
+#[test]
+fn getter_keeps_field_lifetime() {
+    let buffer = [1u8, 2, 3];
+    let data = into_data(Wrapper { data: &buffer });
+    assert_eq!(data, &[1, 2, 3]);
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(set(bound = "exact"))]
+struct Exact {
+    values: Vec<u8>,
+    limit: Option<u64>,
+    #[property(set(bound = "into"))]
+    name: String,
+}
+
+#[test]
+fn exact_bound_helps_inference() {
+    let mut exact = Exact::default();
+    exact
+        .set_values(Vec::new())
+        .set_limit(None)
+        .set_name("name");
+    exact.set_values(vec![1, 2]).set_limit(Some(3));
+    exact.set_limit(Default::default());
+    assert_eq!(exact.values(), &[1, 2]);
+    assert_eq!(exact.limit(), None);
+    assert_eq!(exact.name(), "name");
+}
+
+#[derive(Property, Default)]
+struct Slots {
+    #[property(set(type = "take_replace"))]
+    current: Option<String>,
+    #[property(replace)]
+    previous: Option<String>,
+}
+
+#[test]
+fn take_replace_and_replace() {
+    let mut slots = Slots::default();
+    assert_eq!(slots.set_current("a"), None);
+    assert_eq!(slots.set_current("b"), Some("a".to_owned()));
+    assert_eq!(slots.current(), Some(&"b".to_owned()));
+    // The `replace` method takes the whole `Option`.
+    assert_eq!(slots.replace_previous(Some("a".to_owned())), None);
+    assert_eq!(slots.replace_previous(None), Some("a".to_owned()));
+    assert_eq!(slots.previous(), None);
+}
+
+#[derive(Property, Default)]
+struct Key {
+    #[property(set(type = "from_slice"))]
+    bytes: [u8; 4],
+}
+
+#[test]
+fn from_slice() {
+    let mut key = Key::default();
+    assert!(key.set_bytes(&[1, 2, 3, 4]).is_ok());
+    assert_eq!(key.bytes(), &[1, 2, 3, 4]);
+    assert!(key.set_bytes(&[5, 6, 7]).is_err());
+    assert!(key.set_bytes(&[5, 6, 7, 8, 9]).is_err());
+    assert_eq!(key.bytes(), &[1, 2, 3, 4]);
+}
+
+#[derive(Property, Default)]
+#[property(set(type = "ref_field"))]
+struct Draft {
+    lines: Vec<String>,
+    title: Option<String>,
+}
+
+#[test]
+fn ref_field() {
+    let mut draft = Draft::default();
+    draft.set_lines(vec!["a"]).push("b".to_owned());
+    draft
+        .set_title("title".to_owned())
+        .as_mut()
+        .unwrap()
+        .push('s');
+    assert_eq!(draft.lines(), &["a".to_owned(), "b".to_owned()]);
+    assert_eq!(draft.title(), Some(&"titles".to_owned()));
+}
+
+#[derive(Property, Default)]
+struct Explicit {
+    #[property(set(arg = "&str"))]
+    name: String,
+    #[property(set(arg = "u32"))]
+    total: u64,
+    #[property(set(arg = "&str", convert = "val.to_uppercase()"))]
+    code: String,
+}
+
+#[test]
+fn explicit_arg() {
+    let mut explicit = Explicit::default();
+    explicit.set_name("name").set_total(1).set_code("ab");
+    assert_eq!(explicit.name(), "name");
+    assert_eq!(explicit.total(), 1);
+    assert_eq!(explicit.code(), "AB");
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property)]
+struct Window {
+    #[property(get(type = "head", n = "4"))]
+    first: Vec<u8>,
+    #[property(get(type = "tail", n = "4"))]
+    last: Vec<u8>,
+    #[property(get(type = "head", n = "2"))]
+    front: [u8; 3],
+    #[property(get(type = "tail", n = "5"))]
+    back: [u8; 3],
+}
+
+#[test]
+fn head_and_tail() {
+    let mut window = Window {
+        first: vec![1, 2],
+        last: vec![1, 2],
+        front: [1, 2, 3],
+        back: [1, 2, 3],
+    };
+    assert_eq!(window.first(), &[1, 2]);
+    assert_eq!(window.last(), &[1, 2]);
+    assert_eq!(window.front(), &[1, 2]);
+    assert_eq!(window.back(), &[1, 2, 3]);
+    window.set_first(vec![1, 2, 3, 4, 5, 6]);
+    window.set_last(vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(window.first(), &[1, 2, 3, 4]);
+    assert_eq!(window.last(), &[3, 4, 5, 6]);
+    window.set_first(Vec::<u8>::new());
+    window.set_last(Vec::<u8>::new());
+    assert!(window.first().is_empty());
+    assert!(window.last().is_empty());
+}
+
+#[derive(Property)]
+struct Table {
+    #[property(get(const))]
+    rows: [u16; 3],
+}
+
+const TABLE: Table = Table { rows: [1, 2, 3] };
+const ROWS: &[u16] = TABLE.rows();
+
+#[test]
+fn const_slice_getter() {
+    assert_eq!(ROWS, &[1, 2, 3]);
+    assert_eq!(TABLE.rows().len(), 3);
+}
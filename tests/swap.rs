@@ -0,0 +1,30 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(swap(a, b), swap(public, left, right))]
+pub struct Pair {
+    a: u32,
+    b: u32,
+    left: String,
+    right: String,
+}
+
+#[test]
+fn swap_fields() {
+    let mut pair = Pair::default();
+    pair.set_a(1u32).set_b(2u32).set_left("l").set_right("r");
+    pair.swap_a_b();
+    pair.swap_left_right();
+    assert_eq!((pair.a(), pair.b()), (2, 1));
+    assert_eq!((pair.left(), pair.right()), ("r", "l"));
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+mod grid {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Cell;
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct String(pub u8);
+}
+
+#[derive(Property, Default)]
+struct Qualified {
+    names: std::vec::Vec<u8>,
+    label: ::std::string::String,
+    title: alias::Title,
+    boxed: std::boxed::Box<str>,
+    count: core::option::Option<u32>,
+}
+
+mod alias {
+    pub type Title = std::string::String;
+}
+
+#[derive(Property, Default)]
+struct Shadowed {
+    cell: grid::Cell,
+    text: grid::String,
+}
+
+#[test]
+fn std_paths_are_classified() {
+    let mut qualified = Qualified::default();
+    qualified
+        .set_names(vec![1, 2])
+        .set_label("label")
+        .set_boxed("boxed")
+        .set_count(3);
+    let names: &[u8] = qualified.names();
+    let label: &str = qualified.label();
+    let boxed: &str = qualified.boxed();
+    let count: Option<&u32> = qualified.count();
+    assert_eq!(names, &[1, 2]);
+    assert_eq!(label, "label");
+    assert_eq!(boxed, "boxed");
+    assert_eq!(count, Some(&3));
+    let title: &std::string::String = qualified.title();
+    assert!(title.is_empty());
+}
+
+#[test]
+fn other_paths_are_unhandled() {
+    let mut shadowed = Shadowed::default();
+    shadowed.set_text(grid::String(1));
+    let cell: &grid::Cell = shadowed.cell();
+    let text: &grid::String = shadowed.text();
+    assert_eq!(cell, &grid::Cell);
+    assert_eq!(text, &grid::String(1));
+}
+
+mod wrapper {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Option;
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Vec<T>(pub T);
+}
+
+#[derive(Property)]
+struct Associated<T: Iterator>
+where
+    T::Item: Default,
+{
+    item: <T as Iterator>::Item,
+    option: wrapper::Option,
+    vec: self::wrapper::Vec<u8>,
+    iter: T,
+}
+
+#[test]
+fn associated_and_qualified_paths() {
+    let mut associated = Associated {
+        item: 0u8,
+        option: wrapper::Option,
+        vec: wrapper::Vec(1),
+        iter: std::iter::empty::<u8>(),
+    };
+    associated.set_item(2u8);
+    let item: &u8 = associated.item();
+    let option: &wrapper::Option = associated.option();
+    let vec: &wrapper::Vec<u8> = associated.vec();
+    assert_eq!(*item, 2);
+    assert_eq!(option, &wrapper::Option);
+    assert_eq!(vec, &wrapper::Vec(1));
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+struct Counter {
+    #[property(update)]
+    count: u32,
+    #[property(update(type = "ref"))]
+    names: Vec<String>,
+}
+
+#[derive(Property, Default)]
+#[property(update(disable, type = "ref"), export_names)]
+struct Defaults {
+    #[property(update)]
+    count: u32,
+    name: String,
+}
+
+#[test]
+fn update() {
+    let mut counter = Counter::default();
+    counter.update_count(|count| *count += 1);
+    counter
+        .update_names(|names| names.push("a".to_owned()))
+        .update_names(|names| names.push("b".to_owned()));
+    assert_eq!(counter.count(), 1);
+    assert_eq!(counter.names(), &["a".to_owned(), "b".to_owned()]);
+}
+
+#[test]
+fn container_only_sets_the_default_type() {
+    let mut defaults = Defaults::default();
+    defaults
+        .update_count(|count| *count += 1)
+        .update_count(|count| *count += 1);
+    assert_eq!(defaults.count(), 2);
+    assert_eq!(
+        Defaults::_PROPERTY_METHODS,
+        &[
+            "count",
+            "set_count",
+            "mut_count",
+            "update_count",
+            "name",
+            "set_name",
+            "mut_name"
+        ]
+    );
+}